@@ -0,0 +1,139 @@
+use std::{
+    fs,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use common::Part;
+use serde::Serialize;
+
+use crate::Options;
+
+const DEFAULT_RUNS: usize = 10;
+const DEFAULT_OUTPUT: &str = "target/aoc-bench.json";
+
+pub fn bench_command(options: &Options) -> Result<(), String> {
+    let runs = options.runs.unwrap_or(DEFAULT_RUNS);
+    let mut results = Vec::new();
+    for day in options.days() {
+        let input = options.input(day)?;
+        let solution = options.solution(day)?;
+        // parse once untimed, so a broken input fails before anything is measured
+        let parsed = solution
+            .parse(&input)
+            .map_err(|e| format!("day {day:02}: {e}"))?;
+        let samples = time(runs, || solution.parse(&input));
+        results.push(Measurement::new(day, "parse", &samples));
+        for part in options.parts() {
+            let samples = time(runs, || solution.solve(part, parsed.as_ref()));
+            let step = match part {
+                Part::One => "part1",
+                Part::Two => "part2",
+            };
+            results.push(Measurement::new(day, step, &samples));
+        }
+        results
+            .iter()
+            .filter(|m| m.day == day)
+            .for_each(|m| println!("{m}"));
+    }
+
+    let path = options.output.as_deref().unwrap_or(DEFAULT_OUTPUT);
+    if let Some(dir) = Path::new(path)
+        .parent()
+        .filter(|d| !d.as_os_str().is_empty())
+    {
+        fs::create_dir_all(dir).map_err(|e| format!("can't create '{}': {e}", dir.display()))?;
+    }
+    let json = serde_json::to_string_pretty(&results).expect("results serialize to JSON");
+    fs::write(path, json).map_err(|e| format!("can't write '{path}': {e}"))?;
+    println!("Results written to {path}");
+    Ok(())
+}
+
+/// Durations of `runs` calls of `f`
+fn time<T>(runs: usize, mut f: impl FnMut() -> T) -> Vec<Duration> {
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect()
+}
+
+/// Timings of one step of a day, in nanoseconds
+#[derive(Debug, PartialEq, Serialize)]
+struct Measurement {
+    day: u8,
+    step: &'static str,
+    runs: usize,
+    mean_ns: f64,
+    median_ns: f64,
+    stddev_ns: f64,
+    min_ns: f64,
+    max_ns: f64,
+}
+
+impl Measurement {
+    fn new(day: u8, step: &'static str, samples: &[Duration]) -> Self {
+        let mut nanos = samples
+            .iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        nanos.sort_by(f64::total_cmp);
+        let runs = nanos.len();
+        let mean = nanos.iter().sum::<f64>() / runs as f64;
+        let median = if runs % 2 == 0 {
+            (nanos[runs / 2 - 1] + nanos[runs / 2]) / 2.0
+        } else {
+            nanos[runs / 2]
+        };
+        // sample standard deviation, zero for a single run
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>()
+            / (runs.saturating_sub(1).max(1)) as f64;
+        Self {
+            day,
+            step,
+            runs,
+            mean_ns: mean,
+            median_ns: median,
+            stddev_ns: variance.sqrt(),
+            min_ns: nanos[0],
+            max_ns: nanos[runs - 1],
+        }
+    }
+}
+
+impl std::fmt::Display for Measurement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let duration = |nanos: f64| Duration::from_nanos(nanos as u64);
+        write!(
+            f,
+            "Day {:02} {:<5}  mean {:>10.2?}  median {:>10.2?}  stddev {:>10.2?}",
+            self.day,
+            self.step,
+            duration(self.mean_ns),
+            duration(self.median_ns),
+            duration(self.stddev_ns)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statistics() {
+        let samples = [4, 1, 3, 2].map(Duration::from_nanos);
+        let m = Measurement::new(1, "parse", &samples);
+        assert_eq!((m.mean_ns, m.median_ns), (2.5, 2.5));
+        assert_eq!((m.min_ns, m.max_ns), (1.0, 4.0));
+        assert!((m.stddev_ns - (5.0_f64 / 3.0).sqrt()).abs() < 1e-9);
+
+        let m = Measurement::new(1, "part1", &[Duration::from_nanos(7)]);
+        assert_eq!((m.mean_ns, m.median_ns, m.stddev_ns), (7.0, 7.0, 0.0));
+    }
+}
@@ -0,0 +1,260 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    process::ExitCode,
+};
+
+use common::{Answer, DynSolution, Part};
+use serde::Serialize;
+
+mod bench;
+
+const USAGE: &str = "\
+Usage: aoc run [--day <day>] [--part <part>] [--input <path>] [--set <key>=<value>]... [--json]
+       aoc bench [--day <day>] [--part <part>] [--input <path>] [--set <key>=<value>]... [--runs <n>]
+                 [--output <path>]
+       aoc show --day <day> --view <view> [--input <path>] [--set <key>=<value>]...
+
+Commands:
+    run      Run the solutions, all days and parts unless --day or --part is given
+    bench    Time parsing and the parts of the solutions over repeated runs
+    show     Print a day specific view of the input, like day 16's schedule
+
+Options:
+    --day <day>       Day to run (1-25)
+    --part <part>     Part to run (1 or 2)
+    --input <path>    Puzzle input, defaults to $AOC_INPUT or else dayNN/input.txt.
+                      {day} in the path is replaced by the two digit day,
+                      - reads the input from stdin
+    --set <key>=<value>
+                      Day specific option, like row=10 for day 15, needs --day.
+                      Benching both of day 19's searches compares them:
+                        aoc bench --day 19 --set search=memoised
+                        aoc bench --day 19 --set search=branch-and-bound
+    --view <view>     What show prints:
+                        day 12: route
+                        day 16: schedule (options agents and minutes), mermaid, dot,
+                                mermaid-compressed, dot-compressed
+                        day 17: tower, steps (options dropped and rows)
+    --json            Print the answers as a JSON array
+    --runs <n>        Timed runs of each step, defaults to 10
+    --output <path>   Where bench writes its JSON results, defaults to target/aoc-bench.json";
+
+/// Environment variable with the input path to use when --input isn't given
+const INPUT_VAR: &str = "AOC_INPUT";
+
+/// Placeholder in input paths that is replaced by the day
+const DAY_PLACEHOLDER: &str = "{day}";
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let (command, args) = args.split_first().ok_or("no command given")?;
+    match command.as_str() {
+        "run" => run_command(&Options::parse(args)?),
+        "bench" => bench::bench_command(&Options::parse(args)?),
+        "show" => show_command(&Options::parse(args)?),
+        "-h" | "--help" | "help" => {
+            println!("{USAGE}");
+            Ok(())
+        }
+        other => Err(format!("unknown command '{other}'")),
+    }
+}
+
+#[derive(Debug, Default)]
+struct Options {
+    day: Option<u8>,
+    part: Option<Part>,
+    input: Option<String>,
+    /// Passed to the solution's `configure`
+    settings: Vec<(String, String)>,
+    view: Option<String>,
+    json: bool,
+    runs: Option<usize>,
+    output: Option<String>,
+}
+
+impl Options {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut args = args.iter();
+        while let Some(flag) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("missing value for {flag}"))
+            };
+            match flag.as_str() {
+                "--day" => {
+                    let day = value()?;
+                    let day = day
+                        .parse::<u8>()
+                        .ok()
+                        .filter(|day| aoc::DAYS.contains(day))
+                        .ok_or_else(|| format!("invalid day '{day}'"))?;
+                    options.day = Some(day);
+                }
+                "--part" => {
+                    let part = value()?;
+                    let part = part
+                        .parse::<u8>()
+                        .ok()
+                        .and_then(|part| Part::try_from(part).ok())
+                        .ok_or_else(|| format!("invalid part '{part}'"))?;
+                    options.part = Some(part);
+                }
+                "--input" => options.input = Some(value()?.clone()),
+                "--set" => {
+                    let setting = value()?;
+                    let (key, value) = setting
+                        .split_once('=')
+                        .ok_or_else(|| format!("invalid setting '{setting}'"))?;
+                    options.settings.push((key.to_string(), value.to_string()));
+                }
+                "--view" => options.view = Some(value()?.clone()),
+                "--json" => options.json = true,
+                "--runs" => {
+                    let runs = value()?;
+                    let runs = runs
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|&runs| runs > 0)
+                        .ok_or_else(|| format!("invalid number of runs '{runs}'"))?;
+                    options.runs = Some(runs);
+                }
+                "--output" => options.output = Some(value()?.clone()),
+                other => return Err(format!("unknown option '{other}'")),
+            }
+        }
+        if !options.settings.is_empty() && options.day.is_none() {
+            return Err("--set requires --day".to_string());
+        }
+        let source = if options.input.is_some() {
+            "--input"
+        } else {
+            options.input = env::var(INPUT_VAR).ok().filter(|path| !path.is_empty());
+            INPUT_VAR
+        };
+        if let Some(path) = &options.input {
+            if options.day.is_none() && !path.contains(DAY_PLACEHOLDER) {
+                return Err(format!(
+                    "{source} '{path}' requires --day, or {DAY_PLACEHOLDER} in the path"
+                ));
+            }
+        }
+        Ok(options)
+    }
+
+    fn days(&self) -> Vec<u8> {
+        match self.day {
+            Some(day) => vec![day],
+            None => aoc::DAYS.collect(),
+        }
+    }
+
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![Part::One, Part::Two],
+        }
+    }
+
+    /// The solution for `day` with the settings applied
+    fn solution(&self, day: u8) -> Result<Box<dyn DynSolution>, String> {
+        let mut solution = aoc::solution(day).expect("day is validated");
+        for (key, value) in &self.settings {
+            solution
+                .configure(key, value)
+                .map_err(|e| format!("day {day:02}: {e}"))?;
+        }
+        Ok(solution)
+    }
+
+    /// Reads the puzzle input for `day`
+    fn input(&self, day: u8) -> Result<String, String> {
+        let path = match &self.input {
+            Some(path) => path.replace(DAY_PLACEHOLDER, &format!("{day:02}")),
+            None => format!("day{day:02}/input.txt"),
+        };
+        read_input(&path).map_err(|e| format!("can't read input '{path}': {e}"))
+    }
+}
+
+fn run_command(options: &Options) -> Result<(), String> {
+    let days = options.days();
+    let parts = options.parts();
+    // a single answer is printed bare, so scripts can use it directly
+    let labelled = days.len() * parts.len() > 1;
+    let mut records = Vec::new();
+    for day in days {
+        let input = options.input(day)?;
+        let solution = options.solution(day)?;
+        let parsed = solution
+            .parse(&input)
+            .map_err(|e| format!("day {day:02}: {e}"))?;
+        for &part in &parts {
+            let answer = solution.solve(part, parsed.as_ref());
+            if options.json {
+                records.push(Record {
+                    day,
+                    part: part.into(),
+                    answer,
+                });
+            } else if labelled && matches!(answer, Answer::Grid(_)) {
+                println!("Day {day:02} part {part}:\n{answer}");
+            } else if labelled {
+                println!("Day {day:02} part {part}: {answer}");
+            } else {
+                println!("{answer}");
+            }
+        }
+    }
+    if options.json {
+        let json = serde_json::to_string_pretty(&records).expect("answers serialize to JSON");
+        println!("{json}");
+    }
+    Ok(())
+}
+
+fn show_command(options: &Options) -> Result<(), String> {
+    let day = options.day.ok_or("show requires --day")?;
+    let view = options.view.as_deref().ok_or("show requires --view")?;
+    let input = options.input(day)?;
+    let solution = options.solution(day)?;
+    let parsed = solution
+        .parse(&input)
+        .map_err(|e| format!("day {day:02}: {e}"))?;
+    let shown = solution
+        .show(view, parsed.as_ref())
+        .map_err(|e| format!("day {day:02}: {e}"))?;
+    println!("{shown}");
+    Ok(())
+}
+
+/// Reads the file at `path`, or stdin if `path` is `-`
+fn read_input(path: &str) -> io::Result<String> {
+    if path == "-" {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+/// An answer as printed by `--json`
+#[derive(Serialize)]
+struct Record {
+    day: u8,
+    part: u8,
+    answer: Answer,
+}
@@ -0,0 +1,118 @@
+//! Checks every entry in the `dayNN/fixtures.toml` manifests
+//!
+//! Fixtures marked `slow = true` only run when `AOC_SLOW_FIXTURES` is set.
+
+use std::{collections::BTreeMap, env, fs, path::PathBuf};
+
+use common::{Answer, Part};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Manifest {
+    fixture: Vec<Fixture>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Fixture {
+    /// Input file, relative to the day's directory
+    input: String,
+    part: u8,
+    answer: Expected,
+    /// Passed to the solution's `configure` before parsing
+    #[serde(default)]
+    options: BTreeMap<String, toml::Value>,
+    #[serde(default)]
+    slow: bool,
+}
+
+/// An [`Answer`] as written in TOML
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Expected {
+    Integer(i64),
+    Text(String),
+    Grid(Vec<String>),
+}
+
+impl From<Expected> for Answer {
+    fn from(value: Expected) -> Self {
+        match value {
+            Expected::Integer(n) => Answer::Integer(n.into()),
+            Expected::Text(text) => Answer::Text(text),
+            Expected::Grid(rows) => Answer::Grid(rows),
+        }
+    }
+}
+
+fn check_day(day: u8) {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{day:02}"));
+    let manifest = fs::read_to_string(dir.join("fixtures.toml")).unwrap();
+    let manifest: Manifest = toml::from_str(&manifest).unwrap();
+    let run_slow = env::var_os("AOC_SLOW_FIXTURES").is_some();
+    for fixture in manifest.fixture {
+        if fixture.slow && !run_slow {
+            continue;
+        }
+        let mut solution = aoc::solution(day).unwrap();
+        for (key, value) in &fixture.options {
+            let value = match value {
+                toml::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            solution.configure(key, &value).unwrap();
+        }
+        let input = fs::read_to_string(dir.join(&fixture.input)).unwrap();
+        let parsed = solution.parse(&input).unwrap();
+        let part = Part::try_from(fixture.part).unwrap();
+        let answer = solution.solve(part, parsed.as_ref());
+        assert_eq!(
+            answer,
+            Answer::from(fixture.answer),
+            "day {day:02} part {part} on {}",
+            fixture.input
+        );
+    }
+}
+
+macro_rules! fixture_tests {
+    ($($name:ident => $day:literal),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                check_day($day);
+            }
+        )*
+    };
+}
+
+fixture_tests!(
+    day01 => 1,
+    day02 => 2,
+    day03 => 3,
+    day04 => 4,
+    day05 => 5,
+    day06 => 6,
+    day07 => 7,
+    day08 => 8,
+    day09 => 9,
+    day10 => 10,
+    day11 => 11,
+    day12 => 12,
+    day13 => 13,
+    day14 => 14,
+    day15 => 15,
+    day16 => 16,
+    day17 => 17,
+    day18 => 18,
+    day19 => 19,
+    day20 => 20,
+    day21 => 21,
+    day22 => 22,
+    day23 => 23,
+    day24 => 24,
+    day25 => 25,
+);
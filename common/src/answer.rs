@@ -0,0 +1,81 @@
+use std::fmt;
+
+use serde::Serialize;
+
+/// The answer to one part of a puzzle
+///
+/// Serializes to JSON as a bare number, string or array of rows.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// Rows of pixels that spell the answer, like day 10's CRT
+    Grid(Vec<String>),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{n}"),
+            Answer::Text(text) => f.write_str(text),
+            Answer::Grid(rows) => f.write_str(&rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Self::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(value: Vec<String>) -> Self {
+        Self::Grid(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::from(42_u64).to_string(), "42");
+        assert_eq!(Answer::from("2=-01").to_string(), "2=-01");
+        let grid = Answer::Grid(vec!["#..".to_string(), ".#.".to_string()]);
+        assert_eq!(grid.to_string(), "#..\n.#.");
+    }
+
+    #[test]
+    fn json() {
+        let answers = [
+            Answer::Integer(-3),
+            Answer::Text("2=-01".to_string()),
+            Answer::Grid(vec!["#.".to_string(), ".#".to_string()]),
+        ];
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(json, r##"[-3,"2=-01",["#.",".#"]]"##);
+    }
+}
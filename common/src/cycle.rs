@@ -0,0 +1,74 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+};
+
+/// Where a sequence of states starts repeating itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Index of the first state that comes back
+    pub start: usize,
+    /// Number of states before it comes back
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Index of a state before the end of the first cycle that equals state
+    /// `n`, with the number of whole cycles between them
+    pub fn reduce(&self, n: u64) -> (usize, u64) {
+        let (start, length) = (self.start as u64, self.length as u64);
+        if n < start {
+            return (n as usize, 0);
+        }
+        (
+            ((n - start) % length + start) as usize,
+            (n - start) / length,
+        )
+    }
+}
+
+/// First repetition in `states`, `None` if they run out before any state
+/// comes back
+pub fn find_cycle<K: Eq + Hash>(states: impl IntoIterator<Item = K>) -> Option<Cycle> {
+    let mut seen = HashMap::new();
+    for (i, state) in states.into_iter().enumerate() {
+        match seen.entry(state) {
+            Entry::Occupied(first) => {
+                let start = *first.get();
+                return Some(Cycle {
+                    start,
+                    length: i - start,
+                });
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(i);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_first_repetition() {
+        // 3 -> 10 -> 5 -> 16 -> 8 -> 4 -> 2 -> 1 -> 4
+        let collatz = std::iter::successors(Some(3_u64), |&n| {
+            Some(if n % 2 == 0 { n / 2 } else { 3 * n + 1 })
+        });
+        let cycle = find_cycle(collatz).unwrap();
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 5,
+                length: 3
+            }
+        );
+        assert_eq!(cycle.reduce(2), (2, 0));
+        assert_eq!(cycle.reduce(8), (5, 1));
+        assert_eq!(cycle.reduce(3 * 1000 + 7), (7, 1000));
+        assert_eq!(find_cycle([1, 2, 3]), None);
+    }
+}
@@ -0,0 +1,312 @@
+use std::{
+    num::TryFromIntError,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::Point;
+
+/// Signed 2D point or vector, `y` grows downwards like in a [`Grid`](crate::Grid)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// Signed 3D point or vector
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+/// Implements the vector arithmetic and the component wise helpers shared by
+/// the point types
+macro_rules! impl_point {
+    ($point:ident { $($axis:ident),+ }) => {
+        impl $point {
+            pub const ZERO: Self = Self { $($axis: 0),+ };
+
+            pub const fn new($($axis: i64),+) -> Self {
+                Self { $($axis),+ }
+            }
+
+            /// Sum of the distances along every axis
+            pub fn manhattan(self, other: Self) -> u64 {
+                0 $(+ self.$axis.abs_diff(other.$axis))+
+            }
+
+            /// Smallest coordinate of both points along every axis
+            pub fn min(self, other: Self) -> Self {
+                Self { $($axis: self.$axis.min(other.$axis)),+ }
+            }
+
+            /// Largest coordinate of both points along every axis
+            pub fn max(self, other: Self) -> Self {
+                Self { $($axis: self.$axis.max(other.$axis)),+ }
+            }
+        }
+
+        impl Add for $point {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Self { $($axis: self.$axis + rhs.$axis),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl Sub for $point {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self { $($axis: self.$axis - rhs.$axis),+ }
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl Neg for $point {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self { $($axis: -self.$axis),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = Self;
+
+            fn mul(self, rhs: i64) -> Self::Output {
+                Self { $($axis: self.$axis * rhs),+ }
+            }
+        }
+    };
+}
+
+impl_point!(Point2 { x, y });
+impl_point!(Point3 { x, y, z });
+
+impl Point2 {
+    /// The 4 orthogonal neighbours, in the order of [`Direction::ALL`]
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL.into_iter().map(move |d| self + d.offset())
+    }
+
+    /// All 8 neighbours, row by row
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        (-1..=1)
+            .flat_map(|y| (-1..=1).map(move |x| Self::new(x, y)))
+            .filter(|&offset| offset != Self::ZERO)
+            .map(move |offset| self + offset)
+    }
+}
+
+impl Point3 {
+    /// The 6 neighbours sharing a face with this point
+    pub fn neighbours6(self) -> impl Iterator<Item = Self> {
+        [
+            Self::new(-1, 0, 0),
+            Self::new(1, 0, 0),
+            Self::new(0, -1, 0),
+            Self::new(0, 1, 0),
+            Self::new(0, 0, -1),
+            Self::new(0, 0, 1),
+        ]
+        .into_iter()
+        .map(move |offset| self + offset)
+    }
+}
+
+impl From<Point> for Point2 {
+    fn from(value: Point) -> Self {
+        Self::new(value.x as i64, value.y as i64)
+    }
+}
+
+/// Fails for points with a negative coordinate
+impl TryFrom<Point2> for Point {
+    type Error = TryFromIntError;
+
+    fn try_from(value: Point2) -> Result<Self, Self::Error> {
+        Ok(Self::new(value.x.try_into()?, value.y.try_into()?))
+    }
+}
+
+/// One of the 4 orthogonal directions on a map where `y` grows downwards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+
+impl Direction {
+    /// All directions, clockwise starting from [`Direction::Right`]
+    pub const ALL: [Self; 4] = [Self::Right, Self::Down, Self::Left, Self::Up];
+
+    /// Unit vector pointing this way
+    pub fn offset(self) -> Point2 {
+        match self {
+            Self::Right => Point2::new(1, 0),
+            Self::Down => Point2::new(0, 1),
+            Self::Left => Point2::new(-1, 0),
+            Self::Up => Point2::new(0, -1),
+        }
+    }
+
+    /// The direction of a unit vector, `None` for anything else
+    pub fn from_offset(offset: Point2) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.offset() == offset)
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The arrow puzzles draw for this direction: `>`, `v`, `<` or `^`
+    pub fn arrow(self) -> char {
+        match self {
+            Self::Right => '>',
+            Self::Down => 'v',
+            Self::Left => '<',
+            Self::Up => '^',
+        }
+    }
+
+    pub fn from_arrow(c: char) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.arrow() == c)
+    }
+}
+
+/// Axis aligned rectangle, both corners included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub min: Point2,
+    pub max: Point2,
+}
+
+/// Axis aligned box, both corners included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid {
+    pub min: Point3,
+    pub max: Point3,
+}
+
+/// Implements the helpers shared by the box types
+macro_rules! impl_box {
+    ($box:ident($point:ident) { $($axis:ident),+ }) => {
+        impl $box {
+            pub fn new(min: $point, max: $point) -> Self {
+                Self { min, max }
+            }
+
+            /// Smallest box containing all `points`, `None` if there are none
+            pub fn from_points(points: impl IntoIterator<Item = $point>) -> Option<Self> {
+                let mut points = points.into_iter();
+                let first = points.next()?;
+                Some(points.fold(Self::new(first, first), |bounds, p| {
+                    Self::new(bounds.min.min(p), bounds.max.max(p))
+                }))
+            }
+
+            pub fn contains(&self, point: $point) -> bool {
+                true $(&& (self.min.$axis..=self.max.$axis).contains(&point.$axis))+
+            }
+
+            /// This box with `margin` added on every side
+            pub fn grow(self, margin: i64) -> Self {
+                let margin = $point { $($axis: margin),+ };
+                Self::new(self.min - margin, self.max + margin)
+            }
+
+            /// Number of points inside the box
+            pub fn volume(&self) -> u64 {
+                1 $(* (self.max.$axis - self.min.$axis + 1).max(0) as u64)+
+            }
+        }
+    };
+}
+
+impl_box!(Rect(Point2) { x, y });
+impl_box!(Cuboid(Point3) { x, y, z });
+
+impl Rect {
+    pub fn width(&self) -> u64 {
+        (self.max.x - self.min.x + 1).max(0) as u64
+    }
+
+    pub fn height(&self) -> u64 {
+        (self.max.y - self.min.y + 1).max(0) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(-3, 4);
+        assert_eq!(a + b, Point2::new(-2, 2));
+        assert_eq!(a - b, Point2::new(4, -6));
+        assert_eq!(-a * 2, Point2::new(-2, 4));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(Point3::new(1, 2, 3).manhattan(Point3::ZERO), 6);
+        assert_eq!(Point::try_from(Point2::new(2, 3)), Ok(Point::new(2, 3)));
+        assert!(Point::try_from(a).is_err());
+    }
+
+    #[test]
+    fn directions() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Right.turn_left(), Direction::Up);
+        assert_eq!(Direction::Down.reverse(), Direction::Up);
+        assert_eq!(Direction::from_arrow('<'), Some(Direction::Left));
+        assert_eq!(
+            Direction::from_offset(Point2::new(0, 1)),
+            Some(Direction::Down)
+        );
+        assert_eq!(Direction::from_offset(Point2::new(1, 1)), None);
+        assert_eq!(
+            Direction::Left.offset() + Direction::Right.offset(),
+            Point2::ZERO
+        );
+        assert_eq!(Point2::ZERO.neighbours4().count(), 4);
+        assert_eq!(Point2::ZERO.neighbours8().count(), 8);
+    }
+
+    #[test]
+    fn boxes() {
+        let points = [Point3::new(1, 5, 2), Point3::new(3, 1, 2)];
+        let bounds = Cuboid::from_points(points).unwrap();
+        assert_eq!(
+            bounds,
+            Cuboid::new(Point3::new(1, 1, 2), Point3::new(3, 5, 2))
+        );
+        assert_eq!(bounds.volume(), 15);
+        assert!(bounds.contains(Point3::new(2, 3, 2)));
+        assert!(!bounds.contains(Point3::new(2, 3, 3)));
+        assert_eq!(bounds.grow(1).volume(), 5 * 7 * 3);
+        assert_eq!(Rect::from_points([]), None);
+    }
+}
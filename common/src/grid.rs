@@ -0,0 +1,294 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::ParseError;
+
+/// Position of a cell in a [`Grid`], `y` grows downwards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+/// Offsets of the 4 orthogonal neighbours: up, left, right, down
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets of all 8 neighbours, row by row
+const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Rectangular 2D grid stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Grid of `width` by `height` cells that are all `value`
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Grid from its rows, `None` if they aren't all the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        let cells = rows.into_iter().flatten().collect();
+        Some(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Parses a map with one character per cell, `cell` returns `None` for
+    /// characters that don't belong in the map
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = None;
+        let mut height = 0;
+        let mut line_start = 0;
+        for line in input.split('\n') {
+            let mut line_width = 0;
+            for (i, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    ParseError::at(input, &input[line_start + i..], "a map character")
+                })?;
+                cells.push(value);
+                line_width += 1;
+            }
+            let width = *width.get_or_insert(line_width);
+            if line_width != width {
+                // the row ends, or runs on, at its `width`th character
+                let end = line
+                    .char_indices()
+                    .nth(width)
+                    .map_or(line.len(), |(i, _)| i);
+                let rest = &input[line_start + end..];
+                return Err(ParseError::at(
+                    input,
+                    rest,
+                    format!("rows of {width} characters"),
+                ));
+            }
+            height += 1;
+            line_start += line.len() + 1;
+        }
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self.cells[point.y * self.width + point.x])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Rows from top to bottom
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// Cells of column `x` from top to bottom
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {x} is outside the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Columns from left to right
+    pub fn columns(
+        &self,
+    ) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T> + ExactSizeIterator> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// All positions, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// All cells with their positions, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// Position of the first cell, row by row, that matches `predicate`
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        let i = self.cells.iter().position(predicate)?;
+        Some(Point::new(i % self.width, i / self.width))
+    }
+
+    /// Orthogonal neighbours of `point` that are inside the grid
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.offsets(point, &ORTHOGONAL)
+    }
+
+    /// Orthogonal and diagonal neighbours of `point` that are inside the grid
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.offsets(point, &ADJACENT)
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        point: Point,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Point> + 'a {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let x = point.x.checked_add_signed(dx)?;
+            let y = point.y.checked_add_signed(dy)?;
+            let neighbour = Point::new(x, y);
+            self.contains(neighbour).then_some(neighbour)
+        })
+    }
+
+    /// Grid of the same size with `f` applied to every cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: Point) -> &Self::Output {
+        self.get(index)
+            .unwrap_or_else(|| panic!("{index:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, index: Point) -> &mut Self::Output {
+        self.get_mut(index)
+            .unwrap_or_else(|| panic!("{index:?} is outside the grid"))
+    }
+}
+
+/// Renders the cells row by row, without a trailing newline
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                f.write_str("\n")?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let grid = digits("123\n456").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        let column = grid.column(1).copied().collect::<Vec<_>>();
+        assert_eq!(column, [2, 5]);
+        assert_eq!(grid.position(|&d| d > 4), Some(Point::new(1, 1)));
+        assert_eq!(grid.to_string(), "123\n456");
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = digits("123\n4x6").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        let error = digits("123\n45").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.expected, "rows of 3 characters");
+        let error = digits("123\n4567").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.snippet.as_str()),
+            (2, 4, "7")
+        );
+        let error = Grid::parse("abc\néé", Some).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        let error = Grid::parse("éé\nabc", Some).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.snippet.as_str()),
+            (2, 3, "c")
+        );
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::new(3, 3, ());
+        let corner = grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(corner, [Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbours8(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbours4(Point::new(2, 1)).count(), 3);
+    }
+}
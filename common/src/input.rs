@@ -0,0 +1,51 @@
+use std::borrow::Cow;
+
+/// Strips a byte order mark, converts CRLF line endings to LF and removes
+/// trailing blank lines, so every day sees its input the same way
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let input = input.trim_end_matches(['\r', '\n']);
+    if input.contains("\r\n") {
+        Cow::Owned(input.replace("\r\n", "\n"))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+/// Ways an editor or browser may have saved `input`, for testing that days
+/// give the same answers for all of them
+pub fn input_variants(input: &str) -> Vec<String> {
+    let input = normalize(input);
+    let crlf = input.replace('\n', "\r\n");
+    vec![
+        input.to_string(),
+        format!("{input}\n"),
+        format!("{input}\n\n"),
+        crlf.clone(),
+        format!("{crlf}\r\n"),
+        format!("\u{feff}{input}"),
+        format!("\u{feff}{crlf}\r\n\r\n"),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes() {
+        assert_eq!(normalize("a\nb"), "a\nb");
+        assert_eq!(normalize("a\nb\n\n\n"), "a\nb");
+        assert_eq!(normalize("a\r\n\r\nb\r\n"), "a\n\nb");
+        assert_eq!(normalize("\u{feff}  a\n"), "  a");
+        assert!(matches!(normalize("a\nb\n"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn variants_normalize_to_the_input() {
+        let input = "  a\n\nb c";
+        for variant in input_variants(input) {
+            assert_eq!(normalize(&variant), input);
+        }
+    }
+}
@@ -0,0 +1,17 @@
+mod answer;
+mod cycle;
+mod geometry;
+mod grid;
+mod input;
+mod parse;
+mod search;
+mod solution;
+
+pub use answer::Answer;
+pub use cycle::{find_cycle, Cycle};
+pub use geometry::{Cuboid, Direction, Point2, Point3, Rect};
+pub use grid::{Grid, Point};
+pub use input::{input_variants, normalize};
+pub use parse::{parse_all, ParseError};
+pub use search::{astar, bfs, bfs_distances, dijkstra, Path};
+pub use solution::{DynSolution, Part, Solution};
@@ -0,0 +1,148 @@
+use std::fmt;
+
+use nom::{
+    error::{Error, ErrorKind},
+    IResult,
+};
+
+/// Longest snippet of the offending input kept in a [`ParseError`]
+const SNIPPET_LEN: usize = 30;
+
+/// Error returned when a puzzle input can't be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the offending input, starting at 1
+    pub line: usize,
+    /// Column of the offending input in characters, starting at 1
+    pub column: usize,
+    /// The input from the error to the end of its line, empty at the end of the input
+    pub snippet: String,
+    /// Description of what the parser was looking for
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Error at the start of `rest`, which must be a suffix of `input`
+    pub fn at(input: &str, rest: &str, expected: impl Into<String>) -> Self {
+        let offset = input.len() - rest.len();
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before[line_start..].chars().count() + 1;
+        let snippet = rest
+            .split('\n')
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .take(SNIPPET_LEN)
+            .collect();
+        Self {
+            line,
+            column,
+            snippet,
+            expected: expected.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid input at line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+        if self.snippet.is_empty() {
+            f.write_str("found end of line")
+        } else {
+            write!(f, "found '{}'", self.snippet)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Runs `parser` on the whole of `input`, input it leaves unconsumed is an error too
+pub fn parse_all<'a, T>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<T, ParseError> {
+    match parser(input) {
+        Ok(("", value)) => Ok(value),
+        Ok((rest, _)) => {
+            // lists stop at the separator before the first item they can't parse,
+            // so point at that item rather than at the newline in front of it
+            let rest = match rest.trim_start_matches('\n') {
+                "" => rest,
+                item => item,
+            };
+            Err(ParseError::at(input, rest, "end of input"))
+        }
+        Err(nom::Err::Error(Error { input: rest, code }))
+        | Err(nom::Err::Failure(Error { input: rest, code })) => {
+            Err(ParseError::at(input, rest, describe(code)))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::at(input, "", "more input")),
+    }
+}
+
+/// What a nom parser failing with `kind` was looking for
+fn describe(kind: ErrorKind) -> String {
+    match kind {
+        ErrorKind::Tag => "a keyword or separator".to_string(),
+        ErrorKind::Char => "a specific character".to_string(),
+        ErrorKind::OneOf => "one of the allowed characters".to_string(),
+        ErrorKind::Digit => "a number".to_string(),
+        ErrorKind::Alpha => "a word".to_string(),
+        ErrorKind::Space | ErrorKind::MultiSpace => "whitespace".to_string(),
+        ErrorKind::CrLf => "a line ending".to_string(),
+        ErrorKind::Eof => "end of input".to_string(),
+        kind => kind.description().to_lowercase(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{
+        character::complete::{newline, u32 as nomu32},
+        multi::separated_list1,
+    };
+
+    fn numbers(input: &str) -> IResult<&str, Vec<u32>> {
+        separated_list1(newline, nomu32)(input)
+    }
+
+    #[test]
+    fn parses_whole_input() {
+        assert_eq!(parse_all("1\n2\n3", numbers), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn reports_location() {
+        let error = parse_all("1\n2\nthree\n4", numbers).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError {
+                line: 3,
+                column: 1,
+                snippet: "three".to_string(),
+                expected: "end of input".to_string(),
+            }
+        );
+        let error = parse_all("x", numbers).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.expected, "a number");
+    }
+
+    #[test]
+    fn reports_leftover_input() {
+        let error = parse_all("1\n2\n", numbers).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.snippet, "");
+        assert_eq!(
+            error.to_string(),
+            "invalid input at line 2, column 2: expected end of input, found end of line"
+        );
+    }
+}
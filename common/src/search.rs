@@ -0,0 +1,245 @@
+use std::{
+    cmp::Reverse,
+    collections::{
+        hash_map::Entry::{Occupied, Vacant},
+        BinaryHeap, HashMap, VecDeque,
+    },
+    hash::Hash,
+    ops::Add,
+};
+
+/// A route found by a search, from the start to the goal, both included
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+/// Nodes found so far with the node they were reached from
+struct Visited<N> {
+    nodes: Vec<N>,
+    parents: Vec<usize>,
+    index: HashMap<N, usize>,
+}
+
+impl<N: Eq + Hash + Clone> Visited<N> {
+    fn new(start: N) -> Self {
+        Self {
+            nodes: vec![start.clone()],
+            parents: vec![usize::MAX],
+            index: HashMap::from([(start, 0)]),
+        }
+    }
+
+    /// Nodes from the start to node `i`
+    fn path<C>(&self, mut i: usize, cost: C) -> Path<N, C> {
+        let mut nodes = vec![];
+        while i != usize::MAX {
+            nodes.push(self.nodes[i].clone());
+            i = self.parents[i];
+        }
+        nodes.reverse();
+        Path { cost, nodes }
+    }
+}
+
+/// Shortest path from `start` to a node matching `is_goal` when every step
+/// costs 1, `None` if no goal can be reached
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = Visited::new(start);
+    let mut queue = VecDeque::from([(0, 0)]);
+    while let Some((i, cost)) = queue.pop_front() {
+        if is_goal(&visited.nodes[i]) {
+            return Some(visited.path(i, cost));
+        }
+        for next in neighbours(&visited.nodes[i]) {
+            if let Vacant(entry) = visited.index.entry(next) {
+                let j = visited.nodes.len();
+                visited.nodes.push(entry.key().clone());
+                visited.parents.push(i);
+                entry.insert(j);
+                queue.push_back((j, cost + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Number of steps to every node reachable from the nearest of `starts`
+pub fn bfs_distances<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Vacant(entry) = distances.entry(start.clone()) {
+            entry.insert(0);
+            queue.push_back((start, 0));
+        }
+    }
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in neighbours(&node) {
+            if let Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    distances
+}
+
+/// Cheapest path from `start` to a node matching `is_goal`, `neighbours`
+/// yields the nodes next to a node with the cost of getting there
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], exploring nodes in order of their cost plus `heuristic`,
+/// which must never overestimate the cost left to reach a goal
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut visited = Visited::new(start);
+    let mut costs = vec![C::default()];
+    while let Some(Reverse((_, cost, i))) = queue.pop() {
+        if cost > costs[i] {
+            continue; // a cheaper way here was found after this one was queued
+        }
+        if is_goal(&visited.nodes[i]) {
+            return Some(visited.path(i, cost));
+        }
+        for (next, step) in neighbours(&visited.nodes[i]) {
+            let next_cost = cost + step;
+            let j = match visited.index.entry(next) {
+                Vacant(entry) => {
+                    let j = visited.nodes.len();
+                    visited.nodes.push(entry.key().clone());
+                    visited.parents.push(i);
+                    costs.push(next_cost);
+                    entry.insert(j);
+                    j
+                }
+                Occupied(entry) => {
+                    let j = *entry.get();
+                    if next_cost >= costs[j] {
+                        continue;
+                    }
+                    visited.parents[j] = i;
+                    costs[j] = next_cost;
+                    j
+                }
+            };
+            queue.push(Reverse((
+                next_cost + heuristic(&visited.nodes[j]),
+                next_cost,
+                j,
+            )));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, Point, Point2};
+
+    const MAZE: &str = "\
+..#....
+.##.##.
+....#..
+.##...#
+...#...";
+
+    fn maze() -> Grid<bool> {
+        Grid::parse(MAZE, |c| Some(c == '.')).unwrap()
+    }
+
+    #[test]
+    fn breadth_first() {
+        let maze = maze();
+        let open = |p: &Point| {
+            let p = *p;
+            maze.neighbours4(p).filter(|&n| maze[n]).collect::<Vec<_>>()
+        };
+        let goal = Point::new(6, 4);
+        let path = bfs(Point::new(0, 0), open, |&p| p == goal).unwrap();
+        assert_eq!(path.cost, 10);
+        assert_eq!(path.nodes.len(), 11);
+        assert!(path
+            .nodes
+            .windows(2)
+            .all(|w| Point2::from(w[0]).manhattan(Point2::from(w[1])) == 1));
+        assert_eq!(
+            bfs(Point::new(0, 0), open, |&p| p == Point::new(2, 0)),
+            None
+        );
+
+        let distances = bfs_distances([goal], open);
+        assert_eq!(distances[&Point::new(0, 0)], 10);
+        assert_eq!(distances.len(), maze.iter().filter(|(_, &o)| o).count());
+    }
+
+    #[test]
+    fn weighted() {
+        // the direct edge is dearer than the detour
+        let edges = |&n: &u8| match n {
+            0 => vec![(1, 10), (2, 1)],
+            2 => vec![(3, 1)],
+            3 => vec![(1, 1)],
+            _ => vec![],
+        };
+        let path = dijkstra(0, edges, |&n| n == 1).unwrap();
+        assert_eq!(
+            path,
+            Path {
+                cost: 3,
+                nodes: vec![0, 2, 3, 1]
+            }
+        );
+
+        let maze = maze();
+        let goal = Point::new(6, 4);
+        let steps = |p: &Point| {
+            let p = *p;
+            maze.neighbours4(p)
+                .filter(|&n| maze[n])
+                .map(|n| (n, 1))
+                .collect::<Vec<_>>()
+        };
+        let distance = |p: &Point| Point2::from(*p).manhattan(Point2::from(goal));
+        let path = astar(Point::new(0, 0), steps, distance, |&p| p == goal).unwrap();
+        assert_eq!(path.cost, 10);
+    }
+}
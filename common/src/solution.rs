@@ -0,0 +1,116 @@
+use std::{any::Any, fmt::Display};
+
+use crate::{normalize, Answer, ParseError};
+
+/// A day's puzzle: the input is parsed once and shared by both parts
+pub trait Solution {
+    type Input: 'static;
+    type Output: Into<Answer>;
+
+    /// Parses input that has already been through [`normalize`]
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(&self, input: &Self::Input) -> Self::Output;
+
+    fn part2(&self, input: &Self::Input) -> Self::Output;
+
+    /// Sets a day specific option, like the row day 15 counts in part 1
+    fn configure(&mut self, key: &str, _value: &str) -> Result<(), String> {
+        Err(format!("unknown option '{key}'"))
+    }
+
+    /// Renders a day specific view of the input, like the schedule behind day
+    /// 16's answer, for the runner to print
+    fn show(&self, view: &str, _input: &Self::Input) -> Result<String, String> {
+        Err(format!("unknown view '{view}'"))
+    }
+
+    /// Parses the input as it was read from a file
+    fn load(&self, input: &str) -> Result<Self::Input, ParseError> {
+        self.parse(&normalize(input))
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Self::Output, ParseError> {
+        Ok(self.part1(&self.load(input)?))
+    }
+
+    fn solve_part2(&self, input: &str) -> Result<Self::Output, ParseError> {
+        Ok(self.part2(&self.load(input)?))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl TryFrom<u8> for Part {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::One),
+            2 => Ok(Self::Two),
+            x => Err(x),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(value: Part) -> Self {
+        match value {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.write_str("1"),
+            Part::Two => f.write_str("2"),
+        }
+    }
+}
+
+/// Object safe version of [`Solution`], so days can be stored side by side
+pub trait DynSolution {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    /// Panics if `input` didn't come from this solution's `parse`
+    fn solve(&self, part: Part, input: &dyn Any) -> Answer;
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<(), String>;
+
+    /// Panics if `input` didn't come from this solution's `parse`
+    fn show(&self, view: &str, input: &dyn Any) -> Result<String, String>;
+}
+
+impl<S: Solution> DynSolution for S {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(self.load(input)?))
+    }
+
+    fn solve(&self, part: Part, input: &dyn Any) -> Answer {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input was parsed by this solution");
+        match part {
+            Part::One => self.part1(input).into(),
+            Part::Two => self.part2(input).into(),
+        }
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<(), String> {
+        Solution::configure(self, key, value)
+    }
+
+    fn show(&self, view: &str, input: &dyn Any) -> Result<String, String> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input was parsed by this solution");
+        Solution::show(self, view, input)
+    }
+}
@@ -1,31 +1,47 @@
+use common::{parse_all, Answer, ParseError, Solution};
 use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete::char as nomchar,
-    character::complete::{alpha1, newline, one_of, space0, space1},
-    combinator::recognize,
-    multi::{many1, separated_list1},
+    character::complete::{alpha1, newline, space1},
+    multi::{separated_list0, separated_list1},
     IResult,
 };
 
-pub fn process_part1(input: &str) -> String {
-    "".to_string()
+pub struct Day00;
+
+impl Solution for Day00 {
+    type Input = Vec<Line>;
+    type Output = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_all(input, parse_input)
+    }
+
+    fn part1(&self, _lines: &Self::Input) -> Self::Output {
+        "".to_string()
+    }
+
+    fn part2(&self, _lines: &Self::Input) -> Self::Output {
+        "".to_string()
+    }
 }
 
-pub fn process_part2(input: &str) -> String {
-    "".to_string()
+pub fn process_part1(input: &str) -> Answer {
+    Day00.solve_part1(input).unwrap().into()
 }
 
-type Line<'a> = Vec<&'a str>;
+pub fn process_part2(input: &str) -> Answer {
+    Day00.solve_part2(input).unwrap().into()
+}
+
+pub type Line = Vec<String>;
 
 fn parse_input(input: &str) -> IResult<&str, Vec<Line>> {
-    let (input, lines) = separated_list1(newline, parse_line)(input)?;
+    let (input, lines) = separated_list0(newline, parse_line)(input)?;
     Ok((input, lines))
 }
 
 fn parse_line(input: &str) -> IResult<&str, Line> {
     let (input, line) = separated_list1(space1, alpha1)(input)?;
-    Ok((input, line))
+    Ok((input, line.into_iter().map(String::from).collect()))
 }
 
 #[cfg(test)]
@@ -37,13 +53,21 @@ mod tests {
     fn part1() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part1(&input);
-        assert_eq!(result, "");
+        assert_eq!(result, Answer::Text("".to_string()));
     }
 
     #[test]
     fn part2() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part2(&input);
-        assert_eq!(result, "");
+        assert_eq!(result, Answer::Text("".to_string()));
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Text("".to_string()));
+        }
     }
 }
@@ -0,0 +1,80 @@
+use common::{parse_all, Answer, ParseError, Solution};
+use itertools::Itertools;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{newline, u64 as nomu64},
+    combinator::map,
+    multi::separated_list1,
+    IResult,
+};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<usize>;
+    type Output = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_all(input, parse_input)
+    }
+
+    fn part1(&self, elves: &Self::Input) -> Self::Output {
+        elves[elves.len() - 1]
+    }
+
+    fn part2(&self, elves: &Self::Input) -> Self::Output {
+        elves.iter().rev().take(3).sum::<usize>()
+    }
+}
+
+pub fn process_part1(input: &str) -> Answer {
+    Day01.solve_part1(input).unwrap().into()
+}
+
+pub fn process_part2(input: &str) -> Answer {
+    Day01.solve_part2(input).unwrap().into()
+}
+
+/// Calorie count per elf, sorted with largest calorie count last
+fn parse_input(input: &str) -> IResult<&str, Vec<usize>> {
+    let elf = map(separated_list1(newline, nomu64), |calories| {
+        calories.iter().sum::<u64>() as usize
+    });
+    let (input, elves) = separated_list1(tag("\n\n"), elf)(input)?;
+    Ok((input, elves.into_iter().sorted().collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn part1() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let result = process_part1(&input);
+        assert_eq!(result, Answer::Integer(24000));
+    }
+
+    #[test]
+    fn part2() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let result = process_part2(&input);
+        assert_eq!(result, Answer::Integer(45000));
+    }
+
+    #[test]
+    fn parse_error() {
+        let error = Day01.parse("1000\n2000\n\n3000\nfour").unwrap_err();
+        assert_eq!((error.line, error.column), (5, 1));
+        assert_eq!(error.snippet, "four");
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Integer(24000));
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use std::cmp::Ordering;
+
+use common::{parse_all, Answer, ParseError, Solution};
+use nom::{
+    character::complete::{char as nomchar, newline, one_of},
+    multi::separated_list1,
+    IResult,
+};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    /// The second column is a move in part 1 and a strategy in part 2
+    type Input = Vec<(Rps, Rps)>;
+    type Output = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_all(input, separated_list1(newline, parse_round))
+    }
+
+    fn part1(&self, rounds: &Self::Input) -> Self::Output {
+        rounds.iter().map(score).sum()
+    }
+
+    fn part2(&self, rounds: &Self::Input) -> Self::Output {
+        rounds
+            .iter()
+            .map(|(other, strategy)| {
+                // X, Y and Z were read as rock, paper and scissors
+                let my_move = match strategy {
+                    Rps::Rock => other.lose(),
+                    Rps::Paper => other.clone(),
+                    Rps::Scissors => other.defeat(),
+                };
+                (other.clone(), my_move)
+            })
+            .map(|game| score(&game))
+            .sum()
+    }
+}
+
+pub fn process_part1(input: &str) -> Answer {
+    Day02.solve_part1(input).unwrap().into()
+}
+
+pub fn process_part2(input: &str) -> Answer {
+    Day02.solve_part2(input).unwrap().into()
+}
+
+fn parse_round(input: &str) -> IResult<&str, (Rps, Rps)> {
+    let (input, other) = one_of("ABC")(input)?;
+    let (input, _) = nomchar(' ')(input)?;
+    let (input, strategy) = one_of("XYZ")(input)?;
+    Ok((input, (Rps::from(other), Rps::from(strategy))))
+}
+
+fn score(game: &(Rps, Rps)) -> usize {
+    let mut s = match game.1 {
+        Rps::Rock => 1,
+        Rps::Paper => 2,
+        Rps::Scissors => 3,
+    };
+    s += if game.0 < game.1 {
+        6
+    } else if game.0 == game.1 {
+        3
+    } else {
+        0
+    };
+    s
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum Rps {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Rps {
+    fn from(c: char) -> Self {
+        match c {
+            'A' | 'X' => Self::Rock,
+            'B' | 'Y' => Self::Paper,
+            'C' | 'Z' => Self::Scissors,
+            _ => panic!("{} is not a valid move!", c),
+        }
+    }
+
+    fn defeat(&self) -> Self {
+        match self {
+            Self::Rock => Self::Paper,
+            Self::Paper => Self::Scissors,
+            Self::Scissors => Self::Rock,
+        }
+    }
+
+    fn lose(&self) -> Self {
+        match self {
+            Self::Rock => Self::Scissors,
+            Self::Paper => Self::Rock,
+            Self::Scissors => Self::Paper,
+        }
+    }
+}
+
+impl PartialOrd for Rps {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self {
+            Self::Rock => match other {
+                Self::Rock => Some(Ordering::Equal),
+                Self::Paper => Some(Ordering::Less),
+                Self::Scissors => Some(Ordering::Greater),
+            },
+            Self::Paper => match other {
+                Self::Rock => Some(Ordering::Greater),
+                Self::Paper => Some(Ordering::Equal),
+                Self::Scissors => Some(Ordering::Less),
+            },
+            Self::Scissors => match other {
+                Self::Rock => Some(Ordering::Less),
+                Self::Paper => Some(Ordering::Greater),
+                Self::Scissors => Some(Ordering::Equal),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn part1() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let result = process_part1(&input);
+        assert_eq!(result, Answer::Integer(15));
+    }
+
+    #[test]
+    fn part2() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let result = process_part2(&input);
+        assert_eq!(result, Answer::Integer(12));
+    }
+
+    #[test]
+    fn parse_error() {
+        let error = Day02.parse("A Y\nB W\nC Z").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.snippet, "B W");
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Integer(15));
+        }
+    }
+}
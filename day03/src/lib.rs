@@ -0,0 +1,110 @@
+use std::collections::HashSet;
+
+use common::{parse_all, Answer, ParseError, Solution};
+use nom::{
+    character::complete::{alpha1, newline},
+    combinator::map,
+    multi::separated_list1,
+};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    type Output = u32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_all(input, separated_list1(newline, map(alpha1, String::from)))
+    }
+
+    fn part1(&self, rucksacks: &Self::Input) -> Self::Output {
+        let mut total_priority = 0;
+        for rucksack in rucksacks.iter() {
+            let mid = rucksack.len() / 2;
+            let compartment_1: HashSet<char> = HashSet::from_iter(rucksack[0..mid].chars());
+            let compartment_2: HashSet<char> = HashSet::from_iter(rucksack[mid..].chars());
+            let overlap = compartment_1
+                .intersection(&compartment_2)
+                .last()
+                .expect("Should contain a character!");
+            total_priority += priority(overlap);
+        }
+
+        total_priority
+    }
+
+    fn part2(&self, rucksacks: &Self::Input) -> Self::Output {
+        let mut total_badges = 0;
+        for group in rucksacks.chunks(3) {
+            let mut elf1: HashSet<char> = HashSet::from_iter(group[0].chars());
+            let elf2: HashSet<char> = HashSet::from_iter(group[1].chars());
+            let elf3: HashSet<char> = HashSet::from_iter(group[2].chars());
+            elf1.retain(|x| elf2.contains(x));
+            elf1.retain(|x| elf3.contains(x));
+
+            let overlap = elf1
+                .intersection(&elf1)
+                .last()
+                .expect("Should contain a character!");
+            total_badges += priority(overlap);
+        }
+        total_badges
+    }
+}
+
+pub fn process_part1(input: &str) -> Answer {
+    Day03.solve_part1(input).unwrap().into()
+}
+
+pub fn process_part2(input: &str) -> Answer {
+    Day03.solve_part2(input).unwrap().into()
+}
+
+fn priority(item: &char) -> u32 {
+    if item.is_uppercase() {
+        let lowercase = item
+            .to_lowercase()
+            .next()
+            .expect("Should contain a character!");
+        lowercase.to_digit(36).expect("Should contain a character!") + 17
+    } else {
+        item.to_digit(36).expect("Should contain a character!") - 9
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn part1() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let result = process_part1(&input);
+        assert_eq!(result, Answer::Integer(157));
+    }
+
+    #[test]
+    fn part2() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let result = process_part2(&input);
+        assert_eq!(result, Answer::Integer(70));
+    }
+
+    #[test]
+    fn parse_error() {
+        let error = Day03
+            .parse("vJrwpWtwJgWr\njqHRNq 7FjvQ\nPmmdzq")
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+        assert_eq!(error.snippet, " 7FjvQ");
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Integer(157));
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::HashSet;
+
+use common::{parse_all, Answer, ParseError, Solution};
+use nom::{
+    character::complete::{char as nomchar, newline, u32 as nomu32},
+    multi::separated_list1,
+    sequence::separated_pair,
+    IResult,
+};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Pair>;
+    type Output = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_all(input, separated_list1(newline, parse_pair))
+    }
+
+    fn part1(&self, pairs: &Self::Input) -> Self::Output {
+        pairs
+            .iter()
+            .filter(|(elf1, elf2)| elf1.is_subset(elf2) || elf2.is_subset(elf1))
+            .count()
+    }
+
+    fn part2(&self, pairs: &Self::Input) -> Self::Output {
+        pairs
+            .iter()
+            .filter(|(elf1, elf2)| !elf1.is_disjoint(elf2))
+            .count()
+    }
+}
+
+pub fn process_part1(input: &str) -> Answer {
+    Day04.solve_part1(input).unwrap().into()
+}
+
+pub fn process_part2(input: &str) -> Answer {
+    Day04.solve_part2(input).unwrap().into()
+}
+
+pub type Pair = (HashSet<usize>, HashSet<usize>);
+
+fn parse_pair(input: &str) -> IResult<&str, Pair> {
+    let (input, (elf1, elf2)) =
+        separated_pair(parse_sections, nomchar(','), parse_sections)(input)?;
+    Ok((input, (elf1, elf2)))
+}
+
+/// Sections from a range like "2-4"
+fn parse_sections(input: &str) -> IResult<&str, HashSet<usize>> {
+    let (input, (start, end)) = separated_pair(nomu32, nomchar('-'), nomu32)(input)?;
+    Ok((input, HashSet::from_iter(start as usize..=end as usize)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn part1() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let result = process_part1(&input);
+        assert_eq!(result, Answer::Integer(2));
+    }
+
+    #[test]
+    fn part2() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let result = process_part2(&input);
+        assert_eq!(result, Answer::Integer(4));
+    }
+
+    #[test]
+    fn parse_error() {
+        let error = Day04.parse("2-4,6-8\n2-3;4-5\n5-7,7-9").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.snippet, "2-3;4-5");
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Integer(2));
+        }
+    }
+}
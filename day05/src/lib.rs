@@ -0,0 +1,140 @@
+use common::{parse_all, Answer, ParseError, Solution};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, newline, u32 as nomu32},
+    multi::separated_list1,
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Vec<Stack>, Vec<Move>);
+    type Output = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let (stacks, moves) = parse_all(input, parse_input)?;
+        // moves can only name stacks that are there
+        let mut rest = &input[input.find("\n\n").map_or(0, |i| i + 2)..];
+        for &(_, from, to) in &moves {
+            if !(1..stacks.len()).contains(&from) || !(1..stacks.len()).contains(&to) {
+                let expected = format!("stacks numbered 1 to {}", stacks.len() - 1);
+                return Err(ParseError::at(input, rest, expected));
+            }
+            rest = rest.split_once('\n').map_or("", |(_, next)| next);
+        }
+        Ok((stacks, moves))
+    }
+
+    fn part1(&self, (stacks, moves): &Self::Input) -> Self::Output {
+        let mut stacks = stacks.clone();
+        for &(amount, from, to) in moves.iter() {
+            for _ in 0..amount {
+                let to_move = stacks[from].pop().expect("Should not be empty!");
+                stacks[to].push(to_move);
+            }
+        }
+        tops(stacks)
+    }
+
+    fn part2(&self, (stacks, moves): &Self::Input) -> Self::Output {
+        let mut stacks = stacks.clone();
+        for &(amount, from, to) in moves.iter() {
+            let mut temp_stack = vec![];
+            for _ in 0..amount {
+                temp_stack.push(stacks[from].pop().expect("Should not be empty!"));
+            }
+            while !temp_stack.is_empty() {
+                stacks[to].push(temp_stack.pop().expect("Should not be empty!"))
+            }
+        }
+        tops(stacks)
+    }
+}
+
+pub fn process_part1(input: &str) -> Answer {
+    Day05.solve_part1(input).unwrap().into()
+}
+
+pub fn process_part2(input: &str) -> Answer {
+    Day05.solve_part2(input).unwrap().into()
+}
+
+pub type Stack = Vec<char>;
+
+/// (amount, from, to)
+pub type Move = (usize, usize, usize);
+
+/// Stacks written one per line from bottom to top, then the moves
+fn parse_input(input: &str) -> IResult<&str, (Vec<Stack>, Vec<Move>)> {
+    let (input, (crates, moves)) = separated_pair(
+        separated_list1(newline, alpha1),
+        tag("\n\n"),
+        separated_list1(newline, parse_move),
+    )(input)?;
+    // stacks are numbered from 1, so stack 0 stays empty
+    let mut stacks = vec![vec![]];
+    stacks.extend(crates.into_iter().map(|c| c.chars().collect()));
+    Ok((input, (stacks, moves)))
+}
+
+fn parse_move(input: &str) -> IResult<&str, Move> {
+    let (input, amount) = preceded(tag("move "), nomu32)(input)?;
+    let (input, from) = preceded(tag(" from "), nomu32)(input)?;
+    let (input, to) = preceded(tag(" to "), nomu32)(input)?;
+    Ok((input, (amount as usize, from as usize, to as usize)))
+}
+
+fn tops(stacks: Vec<Stack>) -> String {
+    let mut tops: String = "".to_string();
+    for mut stack in stacks {
+        if !stack.is_empty() {
+            tops.push(stack.pop().expect("Should not be empty!"));
+        }
+    }
+    tops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn part1() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let result = process_part1(&input);
+        assert_eq!(result, Answer::Text("CMZ".to_string()));
+    }
+
+    #[test]
+    fn part2() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let result = process_part2(&input);
+        assert_eq!(result, Answer::Text("MCD".to_string()));
+    }
+
+    #[test]
+    fn parse_error() {
+        let error = Day05
+            .parse("ZN\nMCD\n\nmove 1 from 2 to 1\nmove 3 from 1 3")
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (5, 1));
+        assert_eq!(error.snippet, "move 3 from 1 3");
+
+        let error = Day05
+            .parse("ZN\nMCD\n\nmove 1 from 2 to 1\nmove 1 from 3 to 1")
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (5, 1));
+        assert_eq!(error.expected, "stacks numbered 1 to 2");
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Text("CMZ".to_string()));
+        }
+    }
+}
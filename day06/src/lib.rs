@@ -0,0 +1,80 @@
+use std::collections::HashSet;
+
+use common::{parse_all, Answer, ParseError, Solution};
+use nom::character::complete::alpha1;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<char>;
+    type Output = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let datastream = parse_all(input, alpha1)?;
+        Ok(datastream.chars().collect())
+    }
+
+    fn part1(&self, datastream: &Self::Input) -> Self::Output {
+        first_marker(datastream, 4)
+    }
+
+    fn part2(&self, datastream: &Self::Input) -> Self::Output {
+        first_marker(datastream, 14)
+    }
+}
+
+pub fn process_part1(input: &str) -> Answer {
+    Day06.solve_part1(input).unwrap().into()
+}
+
+pub fn process_part2(input: &str) -> Answer {
+    Day06.solve_part2(input).unwrap().into()
+}
+
+/// Number of characters processed when the first `length` distinct characters are seen
+fn first_marker(datastream: &[char], length: usize) -> usize {
+    for i in (length - 1)..datastream.len() {
+        let s = HashSet::<&char>::from_iter(datastream[(i + 1 - length)..=i].iter());
+        if s.len() == length {
+            return i + 1;
+        }
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn part1() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let result = process_part1(&input);
+        assert_eq!(result, Answer::Integer(10));
+    }
+
+    #[test]
+    fn part2() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let result = process_part2(&input);
+        assert_eq!(result, Answer::Integer(29));
+    }
+
+    #[test]
+    fn parse_error() {
+        let error = Day06
+            .parse("mjqjpqmgbljsphdztnvjfqwrcgsmlb\nmore")
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.snippet, "more");
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Integer(10));
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+
+use common::{parse_all, Answer, ParseError, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{newline, not_line_ending, u64 as nomu64},
+    combinator::{map, value},
+    multi::separated_list1,
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Graph;
+    type Output = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let lines = parse_all(input, separated_list1(newline, Line::parse))?;
+        build_graph(input, &lines)
+    }
+
+    fn part1(&self, graph: &Self::Input) -> Self::Output {
+        folder_sizes(graph).filter(|&size| size < 100_001).sum()
+    }
+
+    fn part2(&self, graph: &Self::Input) -> Self::Output {
+        let filesystem_size = 70_000_000;
+        let root_size = graph.get(&0).expect("There's a root").size_on_disk(graph);
+        let free_space = filesystem_size - root_size;
+        let min_size = 30_000_000 - free_space;
+        folder_sizes(graph)
+            .filter(|&size| size >= min_size)
+            .min()
+            .unwrap()
+    }
+}
+
+pub fn process_part1(input: &str) -> Answer {
+    Day07.solve_part1(input).unwrap().into()
+}
+
+pub fn process_part2(input: &str) -> Answer {
+    Day07.solve_part2(input).unwrap().into()
+}
+
+pub type Graph = HashMap<usize, Path>;
+
+fn children<'a>(graph: &'a mut Graph, id: &usize) -> &'a mut Vec<usize> {
+    if let Some(Path::Folder {
+        children: ref mut c,
+        ..
+    }) = graph.get_mut(id)
+    {
+        c
+    } else {
+        panic! {"can't get children of folder with id: {}", id};
+    }
+}
+
+fn path_of(path: &[&str]) -> String {
+    let mut full_path = String::new();
+    for el in path.iter() {
+        full_path.push('>');
+        full_path.push_str(el);
+    }
+    full_path
+}
+
+#[derive(Debug)]
+pub enum Path {
+    Folder {
+        parent: Option<usize>,
+        children: Vec<usize>,
+    },
+    File {
+        size: usize,
+    },
+}
+
+trait DiskSize {
+    fn size_on_disk(&self, graph: &Graph) -> usize;
+}
+
+impl DiskSize for Path {
+    fn size_on_disk(&self, graph: &Graph) -> usize {
+        match self {
+            Self::Folder { children: c, .. } => {
+                let mut total_size = 0;
+                for child in c.iter() {
+                    let child_size = graph.get(child).unwrap().size_on_disk(graph);
+                    total_size += child_size;
+                }
+                total_size
+            }
+            Self::File { size: s, .. } => *s,
+        }
+    }
+}
+
+/// Sizes of all the folders in the graph
+fn folder_sizes(graph: &Graph) -> impl Iterator<Item = usize> + '_ {
+    graph.values().filter_map(|v| match v {
+        Path::File { .. } => None,
+        Path::Folder { .. } => Some(v.size_on_disk(graph)),
+    })
+}
+
+/// A line of the terminal output
+#[derive(Debug, Clone, Copy)]
+enum Line<'a> {
+    Cd(&'a str),
+    Ls,
+    Dir(&'a str),
+    File(usize, &'a str),
+}
+
+impl<'a> Line<'a> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        alt((
+            map(preceded(tag("$ cd "), not_line_ending), Line::Cd),
+            value(Line::Ls, tag("$ ls")),
+            map(preceded(tag("dir "), not_line_ending), Line::Dir),
+            map(
+                separated_pair(nomu64, tag(" "), not_line_ending),
+                |(size, name)| Line::File(size as usize, name),
+            ),
+        ))(input)
+    }
+}
+
+/// Folders and files seen in the terminal `input`, made of `lines`
+fn build_graph(input: &str, lines: &[Line]) -> Result<Graph, ParseError> {
+    let mut ids: HashMap<String, usize> = HashMap::new();
+    let mut graph: HashMap<usize, Path> = HashMap::new();
+    let mut current_path: Vec<&str> = Vec::new();
+    let mut current_id: Option<usize> = None;
+    let mut rest = input;
+    for &line in lines {
+        match (line, current_id) {
+            (Line::Cd(".."), Some(id)) => match graph.get(&id) {
+                Some(Path::Folder {
+                    parent: Some(parent),
+                    ..
+                }) => {
+                    current_id = Some(*parent);
+                    current_path.pop();
+                }
+                _ => return Err(ParseError::at(input, rest, "a folder to leave")),
+            },
+            (Line::Cd(".."), None) => {
+                return Err(ParseError::at(input, rest, "a folder to leave"));
+            }
+            (Line::Cd(name), _) => {
+                current_path.push(name);
+                let p = path_of(&current_path);
+                if let Some(&id) = ids.get(&p) {
+                    current_id = Some(id);
+                } else {
+                    let id = graph.len();
+                    ids.insert(p, id);
+                    graph.insert(
+                        id,
+                        Path::Folder {
+                            parent: None,
+                            children: Vec::new(),
+                        },
+                    );
+                    current_id = Some(id);
+                }
+            }
+            (Line::Ls, Some(_)) => {}
+            (Line::Dir(name), Some(parent)) | (Line::File(_, name), Some(parent)) => {
+                let id = graph.len();
+                let mut p = path_of(&current_path);
+                p.push('>');
+                p.push_str(name);
+                ids.insert(p, id);
+                let path = match line {
+                    Line::File(size, _) => Path::File { size },
+                    _ => Path::Folder {
+                        parent: Some(parent),
+                        children: Vec::new(),
+                    },
+                };
+                graph.insert(id, path);
+                children(&mut graph, &parent).push(id);
+            }
+            (_, None) => return Err(ParseError::at(input, rest, "a '$ cd' first")),
+        }
+        rest = rest.split_once('\n').map_or("", |(_, next)| next);
+    }
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn part1() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let result = process_part1(&input);
+        assert_eq!(result, Answer::Integer(95437));
+    }
+
+    #[test]
+    fn part2() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let result = process_part2(&input);
+        assert_eq!(result, Answer::Integer(24933642));
+    }
+
+    #[test]
+    fn parse_error() {
+        let error = Day07
+            .parse("$ cd /\n$ ls\ndir a\n14848514\n$ cd a")
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(error.snippet, "14848514");
+
+        let error = Day07.parse("$ cd /\n$ cd ..").unwrap_err();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (2, "a folder to leave")
+        );
+        let error = Day07.parse("$ ls\n10 a").unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (1, "a '$ cd' first"));
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Integer(95437));
+        }
+    }
+}
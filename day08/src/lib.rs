@@ -0,0 +1,105 @@
+use common::{Answer, Grid, ParseError, Point, Solution};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Grid<i8>;
+    type Output = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, |c| c.to_digit(10).map(|d| d as i8))
+    }
+
+    fn part1(&self, trees: &Self::Input) -> Self::Output {
+        let height = trees.height();
+        let width = trees.width();
+        let mut visible = Grid::new(width, height, false);
+        let mut look_along = |line: &mut dyn Iterator<Item = Point>| {
+            let mut highest = -1_i8;
+            for point in line {
+                if trees[point] > highest {
+                    visible[point] = true;
+                    highest = trees[point];
+                }
+            }
+        };
+
+        for y in 0..height {
+            // view from left
+            look_along(&mut (0..width).map(|x| Point::new(x, y)));
+            // view from right
+            look_along(&mut (0..width).rev().map(|x| Point::new(x, y)));
+        }
+        for x in 0..width {
+            // view from top
+            look_along(&mut (0..height).map(|y| Point::new(x, y)));
+            // view from bottom
+            look_along(&mut (0..height).rev().map(|y| Point::new(x, y)));
+        }
+        visible.iter().filter(|(_, &v)| v).count()
+    }
+
+    fn part2(&self, trees: &Self::Input) -> Self::Output {
+        let scenic_score = |point: Point| {
+            let tree_height = trees[point];
+            // trees seen along a line of sight, up to and including the first blocking one
+            let viewing_distance = |line: &mut dyn Iterator<Item = &i8>| {
+                let mut distance = 0;
+                for &tree in line {
+                    distance += 1;
+                    if tree >= tree_height {
+                        break;
+                    }
+                }
+                distance
+            };
+            let row = trees.row(point.y);
+            let left = viewing_distance(&mut row[..point.x].iter().rev());
+            let right = viewing_distance(&mut row[point.x + 1..].iter());
+            let up = viewing_distance(&mut trees.column(point.x).take(point.y).rev());
+            let down = viewing_distance(&mut trees.column(point.x).skip(point.y + 1));
+            left * right * up * down
+        };
+        trees
+            .points()
+            .map(scenic_score)
+            .max()
+            .expect("Should be valid number!")
+    }
+}
+
+pub fn process_part1(input: &str) -> Answer {
+    Day08.solve_part1(input).unwrap().into()
+}
+
+pub fn process_part2(input: &str) -> Answer {
+    Day08.solve_part2(input).unwrap().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn part1() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let result = process_part1(&input);
+        assert_eq!(result, Answer::Integer(21));
+    }
+
+    #[test]
+    fn part2() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let result = process_part2(&input);
+        assert_eq!(result, Answer::Integer(8));
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Integer(21));
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use std::collections::HashSet;
+
+use common::{parse_all, Answer, ParseError, Solution};
+use nom::{
+    character::complete::{char as nomchar, newline, one_of, u32 as nomu32},
+    multi::separated_list1,
+    sequence::separated_pair,
+};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<(char, u32)>;
+    type Output = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let moves = separated_pair(one_of("UDLR"), nomchar(' '), nomu32);
+        parse_all(input, separated_list1(newline, moves))
+    }
+
+    fn part1(&self, moves: &Self::Input) -> Self::Output {
+        tail_positions(moves, 2)
+    }
+
+    fn part2(&self, moves: &Self::Input) -> Self::Output {
+        tail_positions(moves, 10)
+    }
+}
+
+pub fn process_part1(input: &str) -> Answer {
+    Day09.solve_part1(input).unwrap().into()
+}
+
+pub fn process_part2(input: &str) -> Answer {
+    Day09.solve_part2(input).unwrap().into()
+}
+
+/// Number of positions visited by the tail of a rope with `knots` knots
+fn tail_positions(moves: &[(char, u32)], knots: usize) -> usize {
+    let mut rope: Vec<(i32, i32)> = vec![(0, 0); knots];
+    let mut visited: HashSet<(i32, i32)> = HashSet::new();
+
+    for (mv, amount) in moves.iter() {
+        let step = match *mv {
+            'U' => (0, 1),
+            'D' => (0, -1),
+            'L' => (-1, 0),
+            'R' => (1, 0),
+            _ => panic!("Should have valid move!"),
+        };
+        for _ in 0..*amount {
+            rope[0].0 += step.0;
+            rope[0].1 += step.1;
+            for i in 1..knots {
+                let head = rope[i - 1];
+                let tail = &mut rope[i];
+                follow_knot(&head, tail);
+            }
+            visited.insert(rope[knots - 1]);
+        }
+    }
+    visited.len()
+}
+
+fn follow_knot(head: &(i32, i32), tail: &mut (i32, i32)) {
+    let y = head.1 - tail.1;
+    let x = head.0 - tail.0;
+    match x {
+        -2 => match y {
+            -2 => {
+                tail.0 -= 1;
+                tail.1 -= 1;
+            }
+            -1 => {
+                tail.0 -= 1;
+                tail.1 -= 1;
+            }
+            0 => {
+                tail.0 -= 1;
+            }
+            1 => {
+                tail.0 -= 1;
+                tail.1 += 1;
+            }
+            2 => {
+                tail.0 -= 1;
+                tail.1 += 1;
+            }
+            _ => panic!("Can't have this difference!"),
+        },
+        -1 => match y {
+            -2 => {
+                tail.1 -= 1;
+                tail.0 -= 1;
+            }
+            -1 => {}
+            0 => {}
+            1 => {}
+            2 => {
+                tail.1 += 1;
+                tail.0 -= 1;
+            }
+            _ => panic!("Can't have this difference!"),
+        },
+        0 => match y {
+            -2 => {
+                tail.1 -= 1;
+            }
+            -1 => {}
+            0 => {}
+            1 => {}
+            2 => tail.1 += 1,
+            _ => panic!("Can't have this difference!"),
+        },
+        1 => match y {
+            -2 => {
+                tail.1 -= 1;
+                tail.0 += 1;
+            }
+            -1 => {}
+            0 => {}
+            1 => {}
+            2 => {
+                tail.1 += 1;
+                tail.0 += 1;
+            }
+            _ => panic!("Can't have this difference!"),
+        },
+        2 => match y {
+            -2 => {
+                tail.0 += 1;
+                tail.1 -= 1;
+            }
+            -1 => {
+                tail.0 += 1;
+                tail.1 -= 1;
+            }
+            0 => {
+                tail.0 += 1;
+            }
+            1 => {
+                tail.0 += 1;
+                tail.1 += 1;
+            }
+            2 => {
+                tail.0 += 1;
+                tail.1 += 1;
+            }
+            _ => panic!("Should have valid move!"),
+        },
+        _ => panic!("Can't have this difference!"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn part1() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let result = process_part1(&input);
+        assert_eq!(result, Answer::Integer(88));
+    }
+
+    #[test]
+    fn part2() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let result = process_part2(&input);
+        assert_eq!(result, Answer::Integer(36));
+    }
+
+    #[test]
+    fn parse_error() {
+        let error = Day09.parse("R 4\nU 4\nX 3\nD 1").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.snippet, "X 3");
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Integer(88));
+        }
+    }
+}
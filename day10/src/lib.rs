@@ -0,0 +1,136 @@
+use common::{parse_all, Answer, ParseError, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{i32 as nomi32, newline},
+    combinator::{map, value},
+    multi::separated_list1,
+    sequence::preceded,
+};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+    type Output = Answer;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let instruction = alt((
+            value(Instruction::Noop, tag("noop")),
+            map(preceded(tag("addx "), nomi32), Instruction::Addx),
+        ));
+        parse_all(input, separated_list1(newline, instruction))
+    }
+
+    fn part1(&self, instructions: &Self::Input) -> Self::Output {
+        let total: i32 = register_values(instructions)
+            .iter()
+            .enumerate()
+            .map(|(i, register_x)| {
+                let cycle = i as i32 + 1;
+                match cycle {
+                    20 | 60 | 100 | 140 | 180 | 220 => cycle * register_x,
+                    _ => 0,
+                }
+            })
+            .sum();
+        total.into()
+    }
+
+    fn part2(&self, instructions: &Self::Input) -> Self::Output {
+        let pixels = register_values(instructions)
+            .iter()
+            .take(240)
+            .enumerate()
+            .map(|(i, register_x)| {
+                let position = (i % 40) as i32;
+                match position - register_x {
+                    -1..=1 => '#',
+                    _ => '.',
+                }
+            })
+            .collect::<Vec<_>>();
+        let rows = pixels
+            .chunks(40)
+            .map(|row| row.iter().collect::<String>())
+            .collect::<Vec<_>>();
+        Answer::Grid(rows)
+    }
+}
+
+pub fn process_part1(input: &str) -> Answer {
+    Day10.solve_part1(input).unwrap()
+}
+
+pub fn process_part2(input: &str) -> Answer {
+    Day10.solve_part2(input).unwrap()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+/// Value of the X register during each cycle, starting with the first cycle
+fn register_values(instructions: &[Instruction]) -> Vec<i32> {
+    let mut register_x = 1;
+    let mut values = vec![];
+    for instruction in instructions {
+        match instruction {
+            Instruction::Noop => values.push(register_x),
+            Instruction::Addx(num) => {
+                values.push(register_x);
+                values.push(register_x);
+                register_x += num;
+            }
+        }
+    }
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn part1() {
+        let input = fs::read_to_string("./input3.txt").unwrap();
+        let result = process_part1(&input);
+        assert_eq!(result, Answer::Integer(13140));
+    }
+
+    #[test]
+    fn part2() {
+        let input = fs::read_to_string("./input3.txt").unwrap();
+        let result = process_part2(&input);
+        let expected = [
+            "##..##..##..##..##..##..##..##..##..##..",
+            "###...###...###...###...###...###...###.",
+            "####....####....####....####....####....",
+            "#####.....#####.....#####.....#####.....",
+            "######......######......######......####",
+            "#######.......#######.......#######.....",
+        ];
+        assert_eq!(
+            result,
+            Answer::Grid(expected.iter().map(|row| row.to_string()).collect())
+        );
+    }
+
+    #[test]
+    fn parse_error() {
+        let error = Day10.parse("noop\naddx 3\naddx -5\nsubx 1").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(error.snippet, "subx 1");
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./input3.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Integer(13140));
+        }
+    }
+}
@@ -0,0 +1,287 @@
+use std::{collections::VecDeque, rc::Rc};
+
+use common::{parse_all, Answer, ParseError, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{one_of, u64 as nomu64},
+    combinator::{consumed, map, value},
+    multi::{separated_list0, separated_list1},
+    sequence::{delimited, preceded, tuple},
+    IResult,
+};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Troop;
+    type Output = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let monkeys = parse_all(input, parse_input)?;
+        let count = monkeys.len();
+        let mut troop = Troop::new();
+        for (rest, monkey) in monkeys {
+            if monkey.test_mod == 0 {
+                return Err(ParseError::at(input, rest, "a divisor above 0"));
+            }
+            if monkey.throw_true.max(monkey.throw_false) >= count {
+                let expected = format!("throws to monkeys 0 to {}", count - 1);
+                return Err(ParseError::at(input, rest, expected));
+            }
+            troop.add(monkey);
+        }
+        Ok(troop)
+    }
+
+    fn part1(&self, monkeys: &Self::Input) -> Self::Output {
+        let mut monkeys = monkeys.clone();
+        for _ in 0..20 {
+            monkeys.round(false);
+        }
+        monkeys.monkey_business()
+    }
+
+    fn part2(&self, monkeys: &Self::Input) -> Self::Output {
+        let mut monkeys = monkeys.clone();
+        for _ in 0..10_000 {
+            monkeys.round(true);
+        }
+        monkeys.monkey_business()
+    }
+}
+
+pub fn process_part1(input: &str) -> Answer {
+    Day11.solve_part1(input).unwrap().into()
+}
+
+pub fn process_part2(input: &str) -> Answer {
+    Day11.solve_part2(input).unwrap().into()
+}
+
+/// Monkeys, each with the input from its description on
+fn parse_input(input: &str) -> IResult<&str, Vec<(&str, Monkey)>> {
+    separated_list1(tag("\n\n"), |i| {
+        let (rest, monkey) = Monkey::parse(i)?;
+        Ok((rest, (i, monkey)))
+    })(input)
+}
+
+#[derive(Clone)]
+struct Operation {
+    signature: String,
+    func: Rc<dyn Fn(usize) -> usize>,
+}
+
+impl Operation {
+    fn run(&self, x: usize) -> usize {
+        (self.func)(x)
+    }
+}
+
+impl std::fmt::Debug for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.signature)
+    }
+}
+
+impl Operation {
+    /// "old <+ or *> <number or old>"
+    fn parse(input: &str) -> IResult<&str, Self> {
+        let operand = alt((value(None, tag("old")), map(nomu64, |n| Some(n as usize))));
+        let (input, (signature, (symbol, operand))) = consumed(preceded(
+            tag("old "),
+            tuple((one_of("+*"), preceded(tag(" "), operand))),
+        ))(input)?;
+        let func: Rc<dyn Fn(usize) -> usize> = match (symbol, operand) {
+            ('*', None) => Rc::new(|x| x * x),
+            ('*', Some(right)) => Rc::new(move |x| x * right),
+            (_, None) => Rc::new(|x| x + x),
+            (_, Some(right)) => Rc::new(move |x| x + right),
+        };
+        let signature = signature.to_owned();
+        Ok((input, Self { signature, func }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Monkey {
+    index: usize,
+    items: VecDeque<usize>,
+    operation: Operation,
+    test_mod: usize,
+    throw_true: usize,
+    throw_false: usize,
+    inspect_count: usize,
+}
+
+impl Monkey {
+    fn go(&mut self, worried: bool, troop_mod: usize) -> Option<(usize, usize)> {
+        if let Some(item) = self.items.pop_front() {
+            let worry_level = match worried {
+                true => self.operation.run(item) % troop_mod + troop_mod,
+                false => self.operation.run(item) / 3,
+            };
+            let next_monkey = match worry_level % self.test_mod {
+                0 => self.throw_true,
+                _ => self.throw_false,
+            };
+            self.inspect_count += 1;
+            Some((worry_level, next_monkey))
+        } else {
+            None
+        }
+    }
+
+    fn throw(&mut self, value: usize) {
+        self.items.push_back(value);
+    }
+
+    fn count(&self) -> usize {
+        self.inspect_count
+    }
+}
+
+impl Monkey {
+    fn parse(input: &str) -> IResult<&str, Self> {
+        let number = |i| map(nomu64, |n| n as usize)(i);
+        let (input, index) = delimited(tag("Monkey "), number, tag(":"))(input)?;
+        let (input, items) = preceded(
+            tag("\n  Starting items: "),
+            separated_list0(tag(", "), number),
+        )(input)?;
+        let (input, operation) = preceded(tag("\n  Operation: new = "), Operation::parse)(input)?;
+        let (input, test_mod) = preceded(tag("\n  Test: divisible by "), number)(input)?;
+        let (input, throw_true) = preceded(tag("\n    If true: throw to monkey "), number)(input)?;
+        let (input, throw_false) =
+            preceded(tag("\n    If false: throw to monkey "), number)(input)?;
+        let monkey = Self {
+            index,
+            items: items.into(),
+            operation,
+            test_mod,
+            throw_true,
+            throw_false,
+            inspect_count: 0,
+        };
+        Ok((input, monkey))
+    }
+}
+
+#[derive(Clone)]
+pub struct Troop {
+    monkeys: Vec<Monkey>,
+    turn: usize,
+    troop_mod: usize,
+}
+
+impl Troop {
+    fn new() -> Self {
+        Self {
+            monkeys: Vec::new(),
+            turn: 0,
+            troop_mod: 1,
+        }
+    }
+
+    fn add(&mut self, monkey: Monkey) {
+        self.troop_mod *= monkey.test_mod;
+        self.monkeys.push(monkey);
+    }
+
+    fn throw(&mut self, monkey_index: usize, item: usize) {
+        self.monkeys
+            .get_mut(monkey_index)
+            .expect("Monkey should exist")
+            .throw(item);
+    }
+
+    fn go(&mut self, monkey_index: usize, worried: bool) -> Option<(usize, usize)> {
+        let troop_mod = self.troop_mod;
+        self.monkeys.get_mut(monkey_index)?.go(worried, troop_mod)
+    }
+
+    fn round(&mut self, worried: bool) {
+        while self.turn < self.monkeys.len() {
+            let mut deque = VecDeque::new();
+            while let Some(tup) = self.go(self.turn, worried) {
+                deque.push_back(tup)
+            }
+            while let Some((item, next_monkey)) = deque.pop_front() {
+                self.throw(next_monkey, item);
+            }
+            self.turn += 1;
+        }
+        self.turn = 0;
+    }
+
+    fn monkey_business(&self) -> usize {
+        let mut inspections: Vec<usize> =
+            self.monkeys.iter().map(|monkey| monkey.count()).collect();
+        inspections.sort_unstable();
+
+        inspections.pop().expect("Must have two") * inspections.pop().expect("Must have two")
+    }
+}
+
+impl std::fmt::Debug for Troop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut s = String::new();
+        for monkey in self.monkeys.iter() {
+            s.extend(format!("Monkey {}: ", monkey.index).chars());
+            for item in monkey.items.iter() {
+                s.push_str(item.to_string().as_str());
+                s.push(',');
+            }
+            s.push('\n')
+        }
+        f.write_str(s.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn part1() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let result = process_part1(&input);
+        assert_eq!(result, Answer::Integer(10605));
+    }
+
+    #[test]
+    fn part2() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let result = process_part2(&input);
+        assert_eq!(result, Answer::Integer(2713310158));
+    }
+
+    #[test]
+    fn parse_error() {
+        let monkey = |throw| {
+            format!(
+                "Monkey 0:\n  Starting items: 79, 98\n  Operation: new = old * 19\n  \
+                 Test: divisible by 23\n    If true: throw to monkey 1\n    \
+                 If false: throw to monkey {throw}"
+            )
+        };
+        let error = Day11.parse(&monkey("one")).unwrap_err();
+        assert_eq!((error.line, error.column), (6, 31));
+        assert_eq!(error.snippet, "one");
+
+        let input = format!("{}\n\n{}", monkey("0"), monkey("2").replace('0', "1"));
+        let error = Day11.parse(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (8, 1));
+        assert_eq!(error.expected, "throws to monkeys 0 to 1");
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Integer(10605));
+        }
+    }
+}
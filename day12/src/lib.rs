@@ -0,0 +1,195 @@
+use common::{
+    bfs, bfs_distances, Answer, Direction, Grid, ParseError, Path, Point, Point2, Solution,
+};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Heightmap;
+    type Output = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Heightmap::parse(input)
+    }
+
+    fn part1(&self, map: &Self::Input) -> Self::Output {
+        map.route(map.start)
+            .expect("Heightmaps have a route up")
+            .cost
+    }
+
+    fn part2(&self, map: &Self::Input) -> Self::Output {
+        let steps = map.steps_to_end();
+        map.heights
+            .iter()
+            .filter(|(_, &height)| height == 0)
+            .filter_map(|(point, _)| steps[point])
+            .min()
+            .expect("Heightmaps have a route up")
+    }
+
+    fn show(&self, view: &str, map: &Self::Input) -> Result<String, String> {
+        match view {
+            "route" => {
+                let route = map.route(map.start).expect("Heightmaps have a route up");
+                Ok(map.render(&route).to_string())
+            }
+            _ => Err(format!("unknown view '{view}'")),
+        }
+    }
+}
+
+pub fn process_part1(input: &str) -> Answer {
+    Day12.solve_part1(input).unwrap().into()
+}
+
+pub fn process_part2(input: &str) -> Answer {
+    Day12.solve_part2(input).unwrap().into()
+}
+
+/// Elevations from 0 for 'a' to 25 for 'z', with the start and the best signal,
+/// which can be reached from the start
+#[derive(Debug)]
+pub struct Heightmap {
+    heights: Grid<u8>,
+    start: Point,
+    end: Point,
+}
+
+impl Heightmap {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse(input, |c| {
+            (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c)
+        })?;
+        let find = |marker: char| {
+            map.position(|&c| c == marker)
+                .ok_or_else(|| ParseError::at(input, "", format!("a '{marker}' in the map")))
+        };
+        let (start, end) = (find('S')?, find('E')?);
+        let heights = map.map(|&c| match c {
+            'S' => 0,
+            'E' => b'z' - b'a',
+            c => c as u8 - b'a',
+        });
+        let map = Self {
+            heights,
+            start,
+            end,
+        };
+        if map.route(start).is_none() {
+            let rest = &input[input.find('S').unwrap_or_default()..];
+            return Err(ParseError::at(input, rest, "a route from 'S' up to 'E'"));
+        }
+        Ok(map)
+    }
+
+    /// A shortest route from `from` to the end, `None` if there's no way up
+    pub fn route(&self, from: Point) -> Option<Path<Point, usize>> {
+        let heights = &self.heights;
+        let climbable = |&point: &Point| {
+            heights
+                .neighbours4(point)
+                .filter(move |&next| heights[next] <= heights[point] + 1)
+        };
+        bfs(from, climbable, |&point| point == self.end)
+    }
+
+    /// The heightmap with `route` drawn on it in arrows, like the puzzle does
+    pub fn render(&self, route: &Path<Point, usize>) -> Grid<char> {
+        let mut picture = self.heights.map(|&height| (b'a' + height) as char);
+        picture[self.start] = 'S';
+        picture[self.end] = 'E';
+        route.nodes.windows(2).for_each(|step| {
+            let offset = Point2::from(step[1]) - Point2::from(step[0]);
+            let direction = Direction::from_offset(offset).expect("Routes take single steps");
+            picture[step[0]] = direction.arrow();
+        });
+        picture
+    }
+
+    /// Fewest steps from every point to the end, `None` where it can't be reached
+    pub fn steps_to_end(&self) -> Grid<Option<usize>> {
+        let heights = &self.heights;
+        // walk backwards: down at most one step, up as far as we like
+        let steps = bfs_distances([self.end], |&point| {
+            heights
+                .neighbours4(point)
+                .filter(move |&previous| heights[point] <= heights[previous] + 1)
+        });
+        let mut grid = heights.map(|_| None);
+        steps
+            .into_iter()
+            .for_each(|(point, steps)| grid[point] = Some(steps));
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn part1() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let result = process_part1(&input);
+        assert_eq!(result, Answer::Integer(31));
+    }
+
+    #[test]
+    fn part2() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let result = process_part2(&input);
+        assert_eq!(result, Answer::Integer(29));
+    }
+
+    #[test]
+    fn steps_from_anywhere() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let map = Day12.load(&input).unwrap();
+        let steps = map.steps_to_end();
+        assert_eq!(steps[map.start], Some(31));
+        assert_eq!(steps[map.end], Some(0));
+        assert_eq!(steps[Point::new(0, 4)], Some(29));
+    }
+
+    #[test]
+    fn render_route() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let map = Day12.load(&input).unwrap();
+        let route = map.route(map.start).unwrap();
+        assert_eq!(route.cost, 31);
+        let expected = "\
+>>vv<<<<
+abvvv<<^
+acvv>E^^
+acv>>>^^
+ab>>>>>^";
+        assert_eq!(map.render(&route).to_string(), expected);
+        assert_eq!(map.route(Point::new(5, 2)).unwrap().nodes, [map.end]);
+    }
+
+    #[test]
+    fn no_route() {
+        let error = Day12.parse("abcd\nSazE").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "a route from 'S' up to 'E'");
+    }
+
+    #[test]
+    fn show_route() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        let map = Day12.load(&input).unwrap();
+        let route = map.render(&map.route(map.start).unwrap()).to_string();
+        assert_eq!(Day12.show("route", &map), Ok(route));
+        assert!(Day12.show("nothing", &map).is_err());
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./input2.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Integer(31));
+        }
+    }
+}
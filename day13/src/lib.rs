@@ -1,42 +1,63 @@
+use common::{parse_all, Answer, ParseError, Solution};
 use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::{char as nomchar, newline, u8 as nomu8},
-    multi::{many1, separated_list0, separated_list1},
+    multi::{separated_list0, separated_list1},
     sequence::delimited,
     IResult,
 };
 
-pub fn process_part1(input: &str) -> String {
-    let (input, pairs) = parse_input1(input).unwrap();
-    assert!(input.is_empty());
-    let total = pairs
-        .iter()
-        .enumerate()
-        .filter_map(|(i, (a, b))| match a.cmp(b) {
-            std::cmp::Ordering::Less => Some(i + 1),
-            std::cmp::Ordering::Equal => panic!("Packets must not be the same! Error on pair {i}"),
-            std::cmp::Ordering::Greater => None,
-        })
-        .sum::<usize>();
-    total.to_string()
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<(Packet, Packet)>;
+    type Output = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_all(input, parse_input)
+    }
+
+    fn part1(&self, pairs: &Self::Input) -> Self::Output {
+        pairs
+            .iter()
+            .enumerate()
+            .filter_map(|(i, (a, b))| match a.cmp(b) {
+                std::cmp::Ordering::Less => Some(i + 1),
+                std::cmp::Ordering::Equal => {
+                    panic!("Packets must not be the same! Error on pair {i}")
+                }
+                std::cmp::Ordering::Greater => None,
+            })
+            .sum::<usize>()
+    }
+
+    fn part2(&self, pairs: &Self::Input) -> Self::Output {
+        let mut packets = pairs
+            .iter()
+            .flat_map(|(a, b)| [a.clone(), b.clone()])
+            .collect::<Vec<_>>();
+        let (_, first_divider) = Packet::parse("[[2]]").unwrap();
+        let (_, second_divider) = Packet::parse("[[6]]").unwrap();
+        packets.push(first_divider.clone());
+        packets.push(second_divider.clone());
+        packets.sort();
+        let first_index = packets.iter().position(|p| p == &first_divider).unwrap() + 1;
+        let second_index = packets.iter().position(|p| p == &second_divider).unwrap() + 1;
+        first_index * second_index
+    }
 }
 
-pub fn process_part2(input: &str) -> String {
-    let (input, mut packets) = parse_input2(input).unwrap();
-    assert!(input.is_empty());
-    let (_, first_divider) = Packet::parse("[[2]]").unwrap();
-    let (_, second_divider) = Packet::parse("[[6]]").unwrap();
-    packets.push(first_divider.clone());
-    packets.push(second_divider.clone());
-    packets.sort();
-    let first_index = packets.iter().position(|p| p == &first_divider).unwrap() + 1;
-    let second_index = packets.iter().position(|p| p == &second_divider).unwrap() + 1;
-    (first_index * second_index).to_string()
+pub fn process_part1(input: &str) -> Answer {
+    Day13.solve_part1(input).unwrap().into()
+}
+
+pub fn process_part2(input: &str) -> Answer {
+    Day13.solve_part2(input).unwrap().into()
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-struct Packet {
+pub struct Packet {
     data: Data,
 }
 
@@ -125,16 +146,11 @@ impl Ord for Data {
     }
 }
 
-fn parse_input1(input: &str) -> IResult<&str, Vec<(Packet, Packet)>> {
+fn parse_input(input: &str) -> IResult<&str, Vec<(Packet, Packet)>> {
     let (input, lines) = separated_list1(tag("\n\n"), Packet::parse_pair)(input)?;
     Ok((input, lines))
 }
 
-fn parse_input2(input: &str) -> IResult<&str, Vec<Packet>> {
-    let (input, lines) = separated_list1(many1(newline), Packet::parse)(input)?;
-    Ok((input, lines))
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -144,13 +160,29 @@ mod tests {
     fn part1() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part1(&input);
-        assert_eq!(result, "13");
+        assert_eq!(result, Answer::Integer(13));
     }
 
     #[test]
     fn part2() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part2(&input);
-        assert_eq!(result, "140");
+        assert_eq!(result, Answer::Integer(140));
+    }
+
+    #[test]
+    fn parse_error() {
+        let input = "[1,1,3]\n[1,1,5]\n\n[[1],[2,3,x]]\n[[1],4]";
+        let error = Day13.parse(input).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(error.snippet, "[[1],[2,3,x]]");
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Integer(13));
+        }
     }
 }
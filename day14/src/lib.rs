@@ -1,110 +1,107 @@
 use std::fmt::Write;
 
+use common::{parse_all, Answer, Grid, ParseError, Point, Solution};
+
 use nom::{
     bytes::complete::tag,
     character::complete::{char as nomchar, digit1, newline},
-    error::{ErrorKind, ParseError},
+    error::{Error, ErrorKind},
     multi::separated_list1,
     IResult,
 };
 
-pub fn process_part1(input: &str) -> String {
-    let (input, lines) = parse_input(input).unwrap();
-    debug_assert!(input.is_empty());
-    lines.iter().for_each(|line| {
-        assert!(line
-            .windows(2)
-            .all(|w| { w[0].0 == w[1].0 || w[0].1 == w[1].1 }))
-    });
-    let mut cave = vec![vec![Cave::Air; 540]; 170];
-    // fill cave with rocks
-    lines.iter().for_each(|line| {
-        line.windows(2).for_each(|w| {
-            let ((x1, y1), (x2, y2)) = (w[0], w[1]);
-            let ((x1, x2), (y1, y2)) = ((x1.min(x2), x1.max(x2)), (y1.min(y2), y1.max(y2)));
-            if x1 == x2 {
-                let x = x1;
-                #[allow(clippy::needless_range_loop)] // symmetry trumps idioms, clippy!
-                for y in y1..=y2 {
-                    cave[y][x] = Cave::Rock;
-                }
-            } else if y1 == y2 {
-                let y = y1;
-                for x in x1..=x2 {
-                    cave[y][x] = Cave::Rock;
-                }
-            } else {
-                unreachable!("No diagonal walls allowed!")
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Line>;
+    type Output = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let lines = parse_all(input, parse_input)?;
+        let mut offset = 0;
+        for (line, text) in lines.iter().zip(input.split('\n')) {
+            let diagonal = line
+                .windows(2)
+                .position(|w| w[0].0 != w[1].0 && w[0].1 != w[1].1);
+            if let Some(i) = diagonal {
+                // point at the coordinate the segment starts from
+                let start = match i {
+                    0 => 0,
+                    _ => text
+                        .match_indices(" -> ")
+                        .nth(i - 1)
+                        .map_or(0, |(j, _)| j + 4),
+                };
+                let rest = &input[offset + start..];
+                return Err(ParseError::at(
+                    input,
+                    rest,
+                    "only horizontal and vertical segments",
+                ));
+            }
+            offset += text.len() + 1;
+        }
+        Ok(lines)
+    }
+
+    fn part1(&self, lines: &Self::Input) -> Self::Output {
+        let mut cave = cave_with_rocks(lines, 540, 170);
+        // simulate sand until some sand drops
+        let mut falling_sand = FallingSand::new();
+        loop {
+            if falling_sand.fall(&mut cave) {
+                break;
             }
-        })
-    });
-    // simulate sand until some sand drops
-    let mut falling_sand = FallingSand::new();
-    loop {
-        if falling_sand.fall(&mut cave) {
-            break;
         }
+        // count the sand left
+        cave.iter().filter(|(_, &c)| c == Cave::Sand).count()
+    }
+
+    fn part2(&self, lines: &Self::Input) -> Self::Output {
+        let mut cave = cave_with_rocks(lines, 1000, 170);
+        let max_y = lines.iter().flatten().map(|&(_, y)| y).max().unwrap_or(0);
+        cave.row_mut(max_y + 2).fill(Cave::Rock);
+
+        // simulate sand until some sand drops
+        let mut falling_sand = FallingSand::new();
+        loop {
+            falling_sand.fall(&mut cave);
+            if cave[FallingSand::SOURCE] == Cave::Sand {
+                break;
+            }
+        }
+        // count the sand left
+        cave.iter().filter(|(_, &c)| c == Cave::Sand).count()
     }
-    let resting_sand = cave
-        .iter()
-        .map(|row| row.iter().filter(|c| c == &&Cave::Sand).count())
-        .sum::<usize>();
-    // count the sand left
-    resting_sand.to_string()
 }
 
-pub fn process_part2(input: &str) -> String {
-    let (input, lines) = parse_input(input).unwrap();
-    debug_assert!(input.is_empty());
-    lines.iter().for_each(|line| {
-        assert!(line
-            .windows(2)
-            .all(|w| { w[0].0 == w[1].0 || w[0].1 == w[1].1 }))
-    });
-    let mut cave = vec![vec![Cave::Air; 1000]; 170];
-    // fill cave with rocks
-    let mut max_y = 0;
+pub fn process_part1(input: &str) -> Answer {
+    Day14.solve_part1(input).unwrap().into()
+}
+
+pub fn process_part2(input: &str) -> Answer {
+    Day14.solve_part2(input).unwrap().into()
+}
+
+pub type Line = Vec<(usize, usize)>;
+
+/// Cave of `width` by `height` with the rock walls of `lines` filled in
+fn cave_with_rocks(lines: &[Line], width: usize, height: usize) -> Grid<Cave> {
+    let mut cave = Grid::new(width, height, Cave::Air);
     lines.iter().for_each(|line| {
         line.windows(2).for_each(|w| {
             let ((x1, y1), (x2, y2)) = (w[0], w[1]);
             let ((x1, x2), (y1, y2)) = ((x1.min(x2), x1.max(x2)), (y1.min(y2), y1.max(y2)));
-            max_y = max_y.max(y2);
-            if x1 == x2 {
-                let x = x1;
-                #[allow(clippy::needless_range_loop)] // symmetry trumps idioms, clippy!
-                for y in y1..=y2 {
-                    cave[y][x] = Cave::Rock;
-                }
-            } else if y1 == y2 {
-                let y = y1;
+            for y in y1..=y2 {
                 for x in x1..=x2 {
-                    cave[y][x] = Cave::Rock;
+                    cave[Point::new(x, y)] = Cave::Rock;
                 }
-            } else {
-                unreachable!("No diagonal walls allowed!")
             }
         })
     });
-    cave[max_y + 2].fill(Cave::Rock);
-
-    // simulate sand until some sand drops
-    let mut falling_sand = FallingSand::new();
-    loop {
-        falling_sand.fall(&mut cave);
-        if cave[0][500] == Cave::Sand {
-            break;
-        }
-    }
-    let resting_sand = cave
-        .iter()
-        .map(|row| row.iter().filter(|c| c == &&Cave::Sand).count())
-        .sum::<usize>();
-    // count the sand left
-    resting_sand.to_string()
+    cave
 }
 
-type Line = Vec<(usize, usize)>;
-
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum Cave {
     Air,
@@ -130,40 +127,36 @@ impl std::fmt::Debug for Cave {
 }
 
 struct FallingSand {
-    x: usize,
-    y: usize,
+    position: Point,
 }
 
 impl FallingSand {
-    fn new() -> Self {
-        Self { x: 500, y: 0 }
-    }
+    /// Where the sand pours in
+    const SOURCE: Point = Point { x: 500, y: 0 };
 
-    fn reset(&mut self) {
-        self.x = 500;
-        self.y = 0;
+    fn new() -> Self {
+        Self {
+            position: Self::SOURCE,
+        }
     }
 
-    fn fall(&mut self, cave: &mut [Vec<Cave>]) -> bool {
-        if self.y + 1 == cave.len() {
+    fn fall(&mut self, cave: &mut Grid<Cave>) -> bool {
+        let Point { x, y } = self.position;
+        if y + 1 == cave.height() {
             return true;
         }
-        if cave[self.y + 1][self.x] == Cave::Air {
-            self.y += 1;
-            false
-        } else if cave[self.y + 1][self.x - 1] == Cave::Air {
-            self.y += 1;
-            self.x -= 1;
-            false
-        } else if cave[self.y + 1][self.x + 1] == Cave::Air {
-            self.y += 1;
-            self.x += 1;
-            false
-        } else {
-            cave[self.y][self.x] = Cave::Sand;
-            self.reset();
-            false
+        let below = [x, x - 1, x + 1]
+            .into_iter()
+            .map(|x| Point::new(x, y + 1))
+            .find(|&p| cave[p] == Cave::Air);
+        match below {
+            Some(p) => self.position = p,
+            None => {
+                cave[self.position] = Cave::Sand;
+                self.position = Self::SOURCE;
+            }
         }
+        false
     }
 }
 
@@ -189,16 +182,16 @@ fn parse_usize(input: &str) -> IResult<&str, usize> {
     let (input, num) = digit1(input)?;
     let num = num
         .parse::<usize>()
-        .map_err(|_| nom::Err::Error(ParseError::from_error_kind(input, ErrorKind::Digit)))?;
+        .map_err(|_| nom::Err::Error(Error::new(input, ErrorKind::Digit)))?;
     Ok((input, num))
 }
 
 #[allow(dead_code)]
-fn print_cave_rect(cave: &[Vec<Cave>], topleft: (usize, usize), width: usize, height: usize) {
+fn print_cave_rect(cave: &Grid<Cave>, topleft: (usize, usize), width: usize, height: usize) {
     let (x, y) = topleft;
     (y..=(y + height)).for_each(|cur_y| {
-        (x..=(x + width)).for_each(|cur_x| print!("{}", &cave[cur_y][cur_x]));
-        println!();
+        let row = &cave.row(cur_y)[x..=(x + width)];
+        println!("{}", row.iter().map(Cave::to_string).collect::<String>());
     });
 }
 
@@ -211,13 +204,30 @@ mod tests {
     fn part1() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part1(&input);
-        assert_eq!(result, "24");
+        assert_eq!(result, Answer::Integer(24));
     }
 
     #[test]
     fn part2() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part2(&input);
-        assert_eq!(result, "93");
+        assert_eq!(result, Answer::Integer(93));
+    }
+
+    #[test]
+    fn diagonal_segment() {
+        let input = "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 500,6 -> 500,9";
+        let error = Day14.parse(input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 10));
+        assert_eq!(error.snippet, "502,4 -> 500,6 -> 500,9");
+        assert_eq!(error.expected, "only horizontal and vertical segments");
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Integer(24));
+        }
     }
 }
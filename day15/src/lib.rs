@@ -1,5 +1,6 @@
 use std::{collections::HashSet, ops::RangeInclusive};
 
+use common::{parse_all, Answer, ParseError, Point2, Solution};
 use nom::{
     bytes::complete::tag,
     character::complete::{i64 as nomi64, newline},
@@ -7,77 +8,116 @@ use nom::{
     IResult,
 };
 
-pub fn process_part1(input: &str, row: i64) -> String {
-    let (input, sensors) = parse_input(input).unwrap();
-    assert!(input.is_empty());
-    let beacons_in_row = sensors
-        .iter()
-        .map(|s| s.closest_beacon.clone())
-        .filter(|b| b.y == row)
-        .collect::<HashSet<Beacon>>()
-        .len();
-    let ranges = sensors.iter().filter_map(|s| s.range_in_row(row)).collect();
-    let non_beacons = count_coverage(ranges) - beacons_in_row as u64;
-    non_beacons.to_string()
+pub struct Day15 {
+    /// Row to count the positions where a beacon can't be in part 1
+    pub row: i64,
+    /// Range of x and y coordinates the distress beacon is in for part 2
+    pub range: RangeInclusive<i64>,
 }
 
-pub fn process_part2(input: &str, range: RangeInclusive<i64>) -> String {
-    let (input, sensors) = parse_input(input).unwrap();
-    assert!(input.is_empty());
-    let tuning_frequency = find_beacon_tuning_frequency(sensors, range);
-    tuning_frequency.to_string()
+impl Default for Day15 {
+    fn default() -> Self {
+        Self {
+            row: 2_000_000,
+            range: 0..=4_000_000,
+        }
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-struct Beacon {
-    x: i64,
-    y: i64,
-}
+impl Solution for Day15 {
+    type Input = Vec<Sensor>;
+    type Output = u64;
 
-impl Beacon {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        let (input, _) = tag("x=")(input)?;
-        let (input, x) = nomi64(input)?;
-        let (input, _) = tag(", y=")(input)?;
-        let (input, y) = nomi64(input)?;
-        Ok((input, Self { x, y }))
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_all(input, parse_input)
+    }
+
+    fn part1(&self, sensors: &Self::Input) -> Self::Output {
+        let row = self.row;
+        let beacons_in_row = sensors
+            .iter()
+            .map(|s| s.closest_beacon)
+            .filter(|b| b.y == row)
+            .collect::<HashSet<_>>()
+            .len();
+        let ranges = sensors.iter().filter_map(|s| s.range_in_row(row)).collect();
+        count_coverage(ranges) - beacons_in_row as u64
+    }
+
+    fn part2(&self, sensors: &Self::Input) -> Self::Output {
+        find_beacon_tuning_frequency(sensors, self.range.clone()) as u64
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let invalid = || format!("invalid value '{value}' for {key}");
+        match key {
+            "row" => self.row = value.parse().map_err(|_| invalid())?,
+            "range" => {
+                let (start, end) = value.split_once("..=").ok_or_else(invalid)?;
+                let start = start.parse().map_err(|_| invalid())?;
+                let end = end.parse().map_err(|_| invalid())?;
+                self.range = start..=end;
+            }
+            _ => return Err(format!("unknown option '{key}'")),
+        }
+        Ok(())
     }
 }
 
+pub fn process_part1(input: &str, row: i64) -> Answer {
+    let day = Day15 {
+        row,
+        ..Default::default()
+    };
+    day.solve_part1(input).unwrap().into()
+}
+
+pub fn process_part2(input: &str, range: RangeInclusive<i64>) -> Answer {
+    let day = Day15 {
+        range,
+        ..Default::default()
+    };
+    day.solve_part2(input).unwrap().into()
+}
+
+fn parse_position(input: &str) -> IResult<&str, Point2> {
+    let (input, _) = tag("x=")(input)?;
+    let (input, x) = nomi64(input)?;
+    let (input, _) = tag(", y=")(input)?;
+    let (input, y) = nomi64(input)?;
+    Ok((input, Point2::new(x, y)))
+}
+
 #[derive(Debug)]
-struct Sensor {
-    x: i64,
-    y: i64,
-    closest_beacon: Beacon,
+pub struct Sensor {
+    position: Point2,
+    closest_beacon: Point2,
 }
 
 impl Sensor {
     fn parse(input: &str) -> IResult<&str, Self> {
-        let (input, _) = tag("Sensor at x=")(input)?;
-        let (input, x) = nomi64(input)?;
-        let (input, _) = tag(", y=")(input)?;
-        let (input, y) = nomi64(input)?;
+        let (input, _) = tag("Sensor at ")(input)?;
+        let (input, position) = parse_position(input)?;
         let (input, _) = tag(": closest beacon is at ")(input)?;
-        let (input, closest_beacon) = Beacon::parse(input)?;
+        let (input, closest_beacon) = parse_position(input)?;
         Ok((
             input,
             Self {
-                x,
-                y,
+                position,
                 closest_beacon,
             },
         ))
     }
 
     fn range_in_row(&self, row: i64) -> Option<RangeInclusive<i64>> {
-        let distance_to_beacon = (self.x.abs_diff(self.closest_beacon.x)
-            + self.y.abs_diff(self.closest_beacon.y)) as i64;
-        let distance_to_row = row.abs_diff(self.y) as i64;
+        let Point2 { x, y } = self.position;
+        let distance_to_beacon = self.position.manhattan(self.closest_beacon) as i64;
+        let distance_to_row = row.abs_diff(y) as i64;
         if distance_to_row > distance_to_beacon {
             None
         } else {
             let x_remaining = distance_to_beacon - distance_to_row;
-            Some((self.x - x_remaining)..=(self.x + x_remaining))
+            Some((x - x_remaining)..=(x + x_remaining))
         }
     }
 }
@@ -105,7 +145,7 @@ fn count_coverage(ranges: Vec<RangeInclusive<i64>>) -> u64 {
     merged_ranges.into_iter().map(|r| r.count()).sum::<usize>() as u64
 }
 
-fn find_beacon_tuning_frequency(sensors: Vec<Sensor>, range: RangeInclusive<i64>) -> usize {
+fn find_beacon_tuning_frequency(sensors: &[Sensor], range: RangeInclusive<i64>) -> usize {
     'search: for row in range.clone() {
         let mut ranges = sensors
             .iter()
@@ -148,13 +188,21 @@ mod tests {
     fn part1() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part1(&input, 10);
-        assert_eq!(result, "26");
+        assert_eq!(result, Answer::Integer(26));
     }
 
     #[test]
     fn part2() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part2(&input, 0..=20);
-        assert_eq!(result, "56000011");
+        assert_eq!(result, Answer::Integer(56000011));
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant, 10), Answer::Integer(26));
+        }
     }
 }
@@ -1,9 +1,10 @@
 use std::{
-    collections::{HashMap, HashSet, VecDeque},
-    hash::Hash,
-    ops::Not,
+    cmp::Reverse,
+    collections::{BTreeSet, HashMap},
+    fmt::{self, Write},
 };
 
+use common::{bfs_distances, parse_all, Answer, ParseError, Solution};
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -12,56 +13,228 @@ use nom::{
     IResult,
 };
 
-pub fn process_part1(input: &str) -> String {
-    let (input, valves) = parse_input(input).unwrap();
-    assert!(input.is_empty());
-    let bitmap = match valves.len() {
-        10 => 0b1000_0000_1111_1111, // only 7 nodes in the test case graph
-        _ => 0b1000_0000_0000_0000,
-    };
-    let valves: Valves = valves.into();
-    let state = State {
-        current_valve: 0,
-        closed_valves: ClosedValves::new(bitmap),
-        minutes_remaining: 30,
-    };
-    let mut memo = HashMap::new();
-    dfs(&valves, state, &mut memo).to_string()
-}
-
-pub fn process_part2(input: &str) -> String {
-    let (input, valves) = parse_input(input).unwrap();
-    assert!(input.is_empty());
-    let valves: Valves = valves.into();
-    let mut memo: HashMap<State, usize> = HashMap::new();
-    (0b1000_0000_0000_0000_u16..0b1111_1111_1111_1111)
-        .map(|bitmap| {
-            let you = State {
-                current_valve: 0,
-                closed_valves: ClosedValves::new(bitmap),
-                minutes_remaining: 26,
-            };
-            let elephant = State {
-                current_valve: 0,
-                closed_valves: ClosedValves::new(bitmap).inverse(),
-                minutes_remaining: 26,
-            };
-            dfs(&valves, you, &mut memo) + (dfs(&valves, elephant, &mut memo))
-        })
-        .max()
-        .expect("There are options")
-        .to_string()
+pub struct Day16 {
+    /// Agents working together in the schedule shown by the runner
+    pub agents: usize,
+    /// Minutes the agents in the shown schedule have
+    pub minutes: usize,
+}
+
+impl Default for Day16 {
+    /// Shows the schedule of part 2
+    fn default() -> Self {
+        Self {
+            agents: 2,
+            minutes: 26,
+        }
+    }
+}
+
+impl Solution for Day16 {
+    type Input = Tunnels;
+    type Output = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let valves = parse_all(input, parse_input)?;
+        let network = Valves::from(valves.as_slice());
+        if network.len() > ValveSet::CAPACITY {
+            let expected = format!("at most {} valves with a flow rate", ValveSet::CAPACITY - 1);
+            return Err(ParseError::at(input, "", expected));
+        }
+        Ok(Tunnels { valves, network })
+    }
+
+    fn part1(&self, tunnels: &Self::Input) -> Self::Output {
+        tunnels.most_pressure(1, 30)
+    }
+
+    fn part2(&self, tunnels: &Self::Input) -> Self::Output {
+        // you and the elephant, after spending 4 minutes teaching it
+        tunnels.most_pressure(2, 26)
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let invalid = |_| format!("invalid value '{value}' for {key}");
+        match key {
+            "agents" => self.agents = value.parse().map_err(invalid)?,
+            "minutes" => self.minutes = value.parse().map_err(invalid)?,
+            _ => return Err(format!("unknown option '{key}'")),
+        }
+        Ok(())
+    }
+
+    fn show(&self, view: &str, tunnels: &Self::Input) -> Result<String, String> {
+        match view {
+            "schedule" => Ok(tunnels.schedule(self.agents, self.minutes).to_string()),
+            "mermaid" => Ok(tunnels.tunnel_graph().mermaid()),
+            "dot" => Ok(tunnels.tunnel_graph().dot()),
+            "mermaid-compressed" => Ok(tunnels.valve_graph().mermaid()),
+            "dot-compressed" => Ok(tunnels.valve_graph().dot()),
+            _ => Err(format!("unknown view '{view}'")),
+        }
+    }
+}
+
+pub fn process_part1(input: &str) -> Answer {
+    Day16::default().solve_part1(input).unwrap().into()
+}
+
+pub fn process_part2(input: &str) -> Answer {
+    Day16::default().solve_part2(input).unwrap().into()
+}
+
+/// The scan and the network of valves worth opening derived from it
+pub struct Tunnels {
+    valves: Vec<Valve>,
+    network: Valves,
+}
+
+impl Tunnels {
+    /// Most pressure `agents` working together can release in `minutes`, all
+    /// starting at AA
+    pub fn most_pressure(&self, agents: usize, minutes: usize) -> usize {
+        self.schedule(agents, minutes).total()
+    }
+
+    /// Every valve in the scan with the tunnels between them
+    pub fn tunnel_graph(&self) -> Graph {
+        let nodes = self
+            .valves
+            .iter()
+            .map(|v| (v.id.clone(), v.flow_rate))
+            .collect();
+        // tunnels go both ways, so only keep one of each pair
+        let edges = self
+            .valves
+            .iter()
+            .flat_map(|v| {
+                v.tunnels.iter().map(|t| {
+                    let (a, b) = if v.id < *t { (&v.id, t) } else { (t, &v.id) };
+                    (a.clone(), b.clone(), None)
+                })
+            })
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        Graph { nodes, edges }
+    }
+
+    /// AA and the valves worth opening, with the minutes it takes to walk
+    /// between them
+    pub fn valve_graph(&self) -> Graph {
+        let valves = &self.network;
+        let nodes = valves
+            .names
+            .iter()
+            .cloned()
+            .zip(valves.flow_rate.iter().copied())
+            .collect();
+        let edges = valves
+            .distances
+            .iter()
+            .enumerate()
+            .flat_map(|(i, distances)| {
+                distances
+                    .iter()
+                    .filter(move |&&(j, _)| i < j)
+                    .map(move |&(j, distance)| {
+                        let (a, b) = (&valves.names[i], &valves.names[j]);
+                        (a.clone(), b.clone(), Some(distance))
+                    })
+            })
+            .collect();
+        Graph { nodes, edges }
+    }
+
+    /// Valves each of `agents` opens to release the most pressure in `minutes`
+    pub fn schedule(&self, agents: usize, minutes: usize) -> Schedule {
+        let valves = &self.network;
+        let mut best = HashMap::new();
+        let mut route = vec![];
+        explore(
+            valves,
+            0,
+            ValveSet::EMPTY,
+            minutes,
+            0,
+            &mut route,
+            &mut best,
+        );
+        let mut best = best.into_values().collect::<Vec<_>>();
+        best.sort_unstable_by_key(|route| (Reverse(route.pressure), route.opened.0));
+        let (_, chosen) = combine(&best, 0, agents, ValveSet::EMPTY);
+        let mut schedule = chosen
+            .into_iter()
+            .map(|i| {
+                best[i]
+                    .openings
+                    .iter()
+                    .map(|&(valve, minutes_remaining)| Opening {
+                        valve: valves.names[valve].clone(),
+                        minute: minutes - minutes_remaining,
+                        pressure: valves.flow_rate[valve] * minutes_remaining,
+                    })
+                    .collect()
+            })
+            .collect::<Vec<_>>();
+        // the rest have nothing worth doing
+        schedule.resize(agents, vec![]);
+        Schedule { agents: schedule }
+    }
+}
+
+/// Valves opened by each agent in order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    pub agents: Vec<Vec<Opening>>,
+}
+
+impl Schedule {
+    /// Pressure released by all agents together
+    pub fn total(&self) -> usize {
+        self.agents.iter().flatten().map(|o| o.pressure).sum()
+    }
+}
+
+/// Prints every opening in order of time, then the total
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut openings = self
+            .agents
+            .iter()
+            .enumerate()
+            .flat_map(|(agent, openings)| openings.iter().map(move |o| (o.minute, agent + 1, o)))
+            .collect::<Vec<_>>();
+        openings.sort_by_key(|&(minute, agent, _)| (minute, agent));
+        for (minute, agent, opening) in openings {
+            writeln!(
+                f,
+                "minute {minute:>2}: agent {agent} opens {}, releasing {}",
+                opening.valve, opening.pressure
+            )?;
+        }
+        write!(f, "total released: {}", self.total())
+    }
+}
+
+/// A valve opened by an agent, `pressure` is all it releases until the time is up
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Opening {
+    pub valve: String,
+    /// Minute spent opening the valve, counting from 1
+    pub minute: usize,
+    pub pressure: usize,
 }
 
 #[derive(Debug)]
-struct Valve<'a> {
-    id: &'a str,
+struct Valve {
+    id: String,
     flow_rate: usize,
-    tunnels: Vec<&'a str>,
+    tunnels: Vec<String>,
 }
 
-impl<'a> Valve<'a> {
-    fn parse(input: &'a str) -> IResult<&str, Self> {
+impl Valve {
+    fn parse(input: &str) -> IResult<&str, Self> {
         let (input, _) = tag("Valve ")(input)?;
         let (input, id) = alpha1(input)?;
         let (input, _) = tag(" has flow rate=")(input)?;
@@ -75,119 +248,138 @@ impl<'a> Valve<'a> {
         Ok((
             input,
             Self {
-                id,
+                id: id.to_string(),
                 flow_rate,
-                tunnels,
+                tunnels: tunnels.into_iter().map(String::from).collect(),
             },
         ))
     }
+}
 
-    #[allow(dead_code)]
-    fn mermaid(&self) {
-        self.tunnels
-            .iter()
-            .for_each(|t| println!("    {} --- {}", self.id, t));
+/// Valves labelled with their flow rate and the tunnels between them,
+/// weighted by their length in minutes when it isn't 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph {
+    pub nodes: Vec<(String, usize)>,
+    pub edges: Vec<(String, String, Option<usize>)>,
+}
+
+impl Graph {
+    /// Mermaid flowchart, for Markdown that renders it
+    pub fn mermaid(&self) -> String {
+        let mut out = String::from("graph LR\n");
+        for (id, flow_rate) in &self.nodes {
+            writeln!(out, "    {id}[\"{id}: {flow_rate}\"]").unwrap();
+        }
+        for (a, b, length) in &self.edges {
+            match length {
+                Some(length) => writeln!(out, "    {a} ---|{length}| {b}"),
+                None => writeln!(out, "    {a} --- {b}"),
+            }
+            .unwrap();
+        }
+        out
+    }
+
+    /// Graphviz DOT, to render with `dot -Tsvg`
+    pub fn dot(&self) -> String {
+        let mut out = String::from("graph tunnels {\n");
+        for (id, flow_rate) in &self.nodes {
+            writeln!(out, "    {id} [label=\"{id}: {flow_rate}\"];").unwrap();
+        }
+        for (a, b, length) in &self.edges {
+            match length {
+                Some(length) => writeln!(out, "    {a} -- {b} [label={length}];"),
+                None => writeln!(out, "    {a} -- {b};"),
+            }
+            .unwrap();
+        }
+        out.push('}');
+        out
     }
 }
 
+/// The valves worth opening, AA first, with the distances between them
 #[derive(Debug)]
 struct Valves {
-    flow_rate: [u8; 16],
-    distances: [Vec<(usize, usize)>; 16],
+    names: Vec<String>,
+    flow_rate: Vec<usize>,
+    distances: Vec<Vec<(usize, usize)>>,
 }
 
-impl From<Vec<Valve<'_>>> for Valves {
-    fn from(value: Vec<Valve>) -> Self {
-        #[inline(always)]
-        fn pos(names: &[&str], name: &str) -> Option<usize> {
-            names.iter().position(|&n| n == name)
-        }
+impl Valves {
+    fn len(&self) -> usize {
+        self.flow_rate.len()
+    }
+}
 
+impl From<&[Valve]> for Valves {
+    fn from(value: &[Valve]) -> Self {
         let mut names = value
             .iter()
             .filter(|v| v.flow_rate > 0 || v.id == "AA")
-            .map(|v| v.id)
+            .map(|v| v.id.as_str())
             .collect::<Vec<_>>();
         names.sort();
-        let mut flow_rate = [0u8; 16];
-        value.iter().for_each(|v| {
-            if let Some(i) = pos(&names, v.id) {
-                flow_rate[i] = v.flow_rate as u8;
-            }
-        });
-        let mut distances: [Vec<(usize, usize)>; 16] = Default::default();
-        let mut queue = VecDeque::new();
-        let mut visited = HashSet::new();
-        names.iter().for_each(|&start| {
-            let start_i = pos(&names, start).expect("Name is in names");
-            queue.push_back((start, 0_usize));
-            visited.clear();
-            visited.insert(start);
-            while let Some((valve, distance)) = queue.pop_front() {
-                value
-                    .iter()
-                    .find(|&v| v.id == valve)
-                    .expect("Don't ask for the neighbours of a node that doesn't exist")
-                    .tunnels
+        let tunnels = value
+            .iter()
+            .map(|v| (v.id.as_str(), v))
+            .collect::<HashMap<_, _>>();
+        let flow_rate = names.iter().map(|name| tunnels[name].flow_rate).collect();
+        let distances = names
+            .iter()
+            .enumerate()
+            .map(|(start_i, &start)| {
+                let reachable = bfs_distances([start], |valve| {
+                    tunnels
+                        .get(valve)
+                        .expect("Don't ask for the neighbours of a node that doesn't exist")
+                        .tunnels
+                        .iter()
+                        .map(String::as_str)
+                });
+                names
                     .iter()
-                    .cloned()
-                    .for_each(|neighbour| {
-                        if visited.contains(&neighbour) {
-                            return; // continue
-                        }
-                        visited.insert(neighbour);
-                        if let Some(i) = pos(&names, neighbour) {
-                            if i != 0 {
-                                // don't care about going back to AA
-                                distances[start_i].push((i, distance + 1));
-                            }
-                        }
-                        queue.push_back((neighbour, distance + 1));
-                    })
-            }
-        });
+                    .enumerate()
+                    // don't care about going back to AA
+                    .filter(|&(i, _)| i != 0 && i != start_i)
+                    .filter_map(|(i, name)| Some((i, *reachable.get(name)?)))
+                    .collect()
+            })
+            .collect();
 
         Self {
+            names: names.into_iter().map(String::from).collect(),
             flow_rate,
             distances,
         }
     }
 }
 
+/// Set of valves by their index in [`Valves`]
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-struct ClosedValves {
-    bitmap: u16,
-}
+struct ValveSet(u64);
 
-impl ClosedValves {
-    const VALVE: u16 = 0b1000_0000_0000_0000;
+impl ValveSet {
+    const CAPACITY: usize = u64::BITS as usize;
 
-    fn new(bitmap: u16) -> Self {
-        Self { bitmap }
-    }
+    const EMPTY: Self = Self(0);
 
-    fn with_open(&self, valve: usize) -> Self {
-        Self {
-            bitmap: self.bitmap | (Self::VALVE >> valve),
-        }
+    fn with(self, valve: usize) -> Self {
+        Self(self.0 | 1 << valve)
     }
 
-    fn is_open(&self, valve: usize) -> bool {
-        (self.bitmap & (Self::VALVE >> valve)) > 0
+    fn contains(self, valve: usize) -> bool {
+        self.0 & 1 << valve != 0
     }
 
-    fn inverse(&self) -> Self {
-        Self {
-            bitmap: self.bitmap.not() | Self::VALVE,
-        }
+    fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
     }
-}
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-struct State {
-    current_valve: usize,
-    closed_valves: ClosedValves,
-    minutes_remaining: usize,
+    fn is_disjoint(self, other: Self) -> bool {
+        self.0 & other.0 == 0
+    }
 }
 
 fn parse_input(input: &str) -> IResult<&str, Vec<Valve>> {
@@ -195,31 +387,75 @@ fn parse_input(input: &str) -> IResult<&str, Vec<Valve>> {
     Ok((input, lines))
 }
 
-fn dfs(valves: &Valves, state: State, memo: &mut HashMap<State, usize>) -> usize {
-    if let Some(value) = memo.get(&state) {
-        return *value;
+/// Valves one agent opens, with the minutes remaining after opening each
+struct Route {
+    opened: ValveSet,
+    pressure: usize,
+    openings: Vec<(usize, usize)>,
+}
+
+/// Records in `best` the route releasing the most pressure for every set of
+/// valves a single agent can open in the time left, `route` is the way here
+fn explore(
+    valves: &Valves,
+    current_valve: usize,
+    opened: ValveSet,
+    minutes_remaining: usize,
+    pressure: usize,
+    route: &mut Vec<(usize, usize)>,
+    best: &mut HashMap<ValveSet, Route>,
+) {
+    let most = best.entry(opened).or_insert_with(|| Route {
+        opened,
+        pressure,
+        openings: route.clone(),
+    });
+    if pressure > most.pressure {
+        most.pressure = pressure;
+        most.openings.clone_from(route);
+    }
+    for &(neighbour, distance) in &valves.distances[current_valve] {
+        let remaining = minutes_remaining.saturating_sub(distance + 1);
+        if opened.contains(neighbour) || remaining == 0 {
+            continue;
+        }
+        route.push((neighbour, remaining));
+        explore(
+            valves,
+            neighbour,
+            opened.with(neighbour),
+            remaining,
+            pressure + valves.flow_rate[neighbour] * remaining,
+            route,
+            best,
+        );
+        route.pop();
+    }
+}
+
+/// Most pressure `agents` release taking routes with disjoint sets of valves
+/// from `best[from..]`, which is sorted by decreasing pressure, none of them
+/// opening valves already `taken`, with the index of every route taken
+fn combine(best: &[Route], from: usize, agents: usize, taken: ValveSet) -> (usize, Vec<usize>) {
+    let mut most = (0, vec![]);
+    if agents == 0 {
+        return most;
     }
-    let max = valves.distances[state.current_valve]
-        .iter()
-        .map(|&(neighbour, distance)| {
-            if state.closed_valves.is_open(neighbour) {
-                return 0; // continue
-            }
-            let remaining = state.minutes_remaining.saturating_sub(distance + 1);
-            if remaining == 0 {
-                return 0;
+    for (i, route) in best.iter().enumerate().skip(from) {
+        if route.pressure * agents <= most.0 {
+            break; // no agent does better than this one from here on
+        }
+        if route.opened.is_disjoint(taken) {
+            // agents are interchangeable, so the others pick further down the list
+            let (pressure, mut others) =
+                combine(best, i + 1, agents - 1, taken.union(route.opened));
+            if route.pressure + pressure > most.0 {
+                others.insert(0, i);
+                most = (route.pressure + pressure, others);
             }
-            let new_state = State {
-                current_valve: neighbour,
-                closed_valves: state.closed_valves.with_open(neighbour),
-                minutes_remaining: remaining,
-            };
-            dfs(valves, new_state, memo) + valves.flow_rate[neighbour] as usize * remaining
-        })
-        .max()
-        .expect("Valves is non-empty");
-    memo.insert(state, max);
-    max
+        }
+    }
+    most
 }
 
 #[cfg(test)]
@@ -231,13 +467,181 @@ mod tests {
     fn part1() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part1(&input);
-        assert_eq!(result, "1651");
+        assert_eq!(result, Answer::Integer(1651));
     }
 
     #[test]
     fn part2() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part2(&input);
-        assert_eq!(result, "1707");
+        assert_eq!(result, Answer::Integer(1707));
+    }
+
+    /// Valve `i` of a generated scan, AA for 0
+    fn name(i: usize) -> String {
+        [i / 26, i % 26]
+            .iter()
+            .map(|&c| (b'A' + c as u8) as char)
+            .collect()
+    }
+
+    /// Scan with `arms` tunnels out of AA, each passing `valves` valves with a flow
+    /// rate of `flow` that are `gap` minutes apart, with broken valves in between
+    fn arms(arms: usize, valves: usize, gap: usize, flow: usize) -> String {
+        let mut tunnels = vec![vec![]; 1 + arms * valves * gap];
+        for arm in 0..arms {
+            let mut previous = 0;
+            for step in 0..valves * gap {
+                let i = 1 + arm * valves * gap + step;
+                tunnels[previous].push(i);
+                tunnels[i].push(previous);
+                previous = i;
+            }
+        }
+        tunnels
+            .iter()
+            .enumerate()
+            .map(|(i, tunnels)| {
+                let rate = if i > 0 && i % gap == 0 { flow } else { 0 };
+                let tunnels = tunnels.iter().map(|&t| name(t)).collect::<Vec<_>>();
+                format!(
+                    "Valve {} has flow rate={rate}; tunnels lead to valves {}",
+                    name(i),
+                    tunnels.join(", ")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Best pressure for one agent walking out along an arm, opening every valve
+    fn along_an_arm(minutes: usize, gap: usize, flow: usize) -> usize {
+        (1..)
+            .map(|k| (gap + 1) * k)
+            .take_while(|&opened| opened < minutes)
+            .map(|opened| flow * (minutes - opened))
+            .sum()
+    }
+
+    #[test]
+    fn many_valves() {
+        let input = arms(2, 20, 4, 3);
+        assert_eq!(Day16::default().load(&input).unwrap().network.len(), 41);
+        let expected = along_an_arm(30, 4, 3);
+        assert_eq!(process_part1(&input), Answer::Integer(expected as i128));
+
+        let expected = 2 * along_an_arm(26, 4, 3);
+        assert_eq!(process_part2(&input), Answer::Integer(expected as i128));
+    }
+
+    /// (valve, minute) of every opening of each agent
+    fn timeline(schedule: &Schedule) -> Vec<Vec<(&str, usize)>> {
+        schedule
+            .agents
+            .iter()
+            .map(|openings| {
+                openings
+                    .iter()
+                    .map(|o| (o.valve.as_str(), o.minute))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn schedule() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        let tunnels = Day16::default().load(&input).unwrap();
+
+        let alone = tunnels.schedule(1, 30);
+        assert_eq!(
+            timeline(&alone),
+            [[
+                ("DD", 2),
+                ("BB", 5),
+                ("JJ", 9),
+                ("HH", 17),
+                ("EE", 21),
+                ("CC", 24)
+            ]]
+        );
+        assert_eq!(alone.agents[0][0].pressure, 20 * 28);
+        assert_eq!(alone.total(), 1651);
+
+        let together = tunnels.schedule(2, 26);
+        let mut together = timeline(&together);
+        together.sort();
+        assert_eq!(
+            together,
+            [
+                vec![("DD", 2), ("HH", 7), ("EE", 11)],
+                vec![("JJ", 3), ("BB", 7), ("CC", 9)]
+            ]
+        );
+
+        // more agents than valves worth opening, so some stay at AA
+        let crowd = tunnels.schedule(8, 26);
+        assert_eq!(crowd.agents.len(), 8);
+        assert!(crowd.agents.iter().filter(|a| a.is_empty()).count() >= 2);
+        let total = tunnels.most_pressure(6, 26);
+        assert!(crowd
+            .to_string()
+            .ends_with(&format!("total released: {total}")));
+    }
+
+    #[test]
+    fn graphs() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        let tunnels = Day16::default().load(&input).unwrap();
+
+        let mermaid = tunnels.tunnel_graph().mermaid();
+        assert!(mermaid.starts_with("graph LR\n    AA[\"AA: 0\"]\n    BB[\"BB: 13\"]\n"));
+        assert!(mermaid.contains("\n    AA --- DD\n"));
+        assert!(!mermaid.contains("DD --- AA"));
+        assert_eq!(mermaid.lines().count(), 1 + 10 + 10);
+
+        let dot = tunnels.valve_graph().dot();
+        assert!(dot.starts_with("graph tunnels {\n    AA [label=\"AA: 0\"];\n"));
+        assert!(dot.contains("\n    BB -- HH [label=6];\n"));
+        assert!(dot.ends_with('}'));
+        // AA and the 6 valves worth opening, all connected
+        assert_eq!(dot.lines().count(), 2 + 7 + 7 * 6 / 2);
+    }
+
+    #[test]
+    fn more_agents() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        let tunnels = Day16::default().load(&input).unwrap();
+        assert_eq!(tunnels.most_pressure(0, 30), 0);
+        assert_eq!(tunnels.most_pressure(2, 0), 0);
+        // enough hands to open everything, so extra agents don't help
+        assert_eq!(tunnels.most_pressure(6, 26), tunnels.most_pressure(7, 26));
+
+        let tunnels = Day16::default().load(&arms(3, 10, 4, 3)).unwrap();
+        assert_eq!(tunnels.most_pressure(3, 20), 3 * along_an_arm(20, 4, 3));
+        assert_eq!(tunnels.most_pressure(2, 20), 2 * along_an_arm(20, 4, 3));
+    }
+
+    #[test]
+    fn configure() {
+        let mut day = Day16::default();
+        day.configure("agents", "3").unwrap();
+        assert_eq!(day.agents, 3);
+        assert_eq!(
+            day.configure("agents", "many"),
+            Err("invalid value 'many' for agents".to_string())
+        );
+        assert_eq!(
+            day.configure("foo", "bar"),
+            Err("unknown option 'foo'".to_string())
+        );
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Integer(1651));
+        }
     }
 }
@@ -1,88 +1,203 @@
-#![allow(unstable_name_collisions)]
-// great if Itertools features are stabilised into std, but don't bitch at me until they are!
-use std::collections::HashSet;
+use std::{
+    collections::{HashSet, VecDeque},
+    iter,
+    str::FromStr,
+};
 
-use itertools::Itertools;
+use common::{
+    bfs_distances, find_cycle, normalize, parse_all, Answer, Grid, ParseError, Point2, Rect,
+    Solution,
+};
 
 use nom::{character::complete::one_of, multi::many1, IResult};
 
-pub fn process_part1(input: &str) -> String {
-    let (input, jets) = parse_input(input).unwrap();
-    assert!(input.is_empty());
-    let mut jetstream = jets.iter().cycle().intersperse(&Jet::Down);
-    let mut cave = Cave {
-        width: 7,
-        rocks_count: 2022,
-        next_rock_idx: 0,
-        rocks: HashSet::new(),
-        highest_rock: 0,
-    };
-    while let Some(mut rock) = cave.next() {
-        loop {
-            if rock.push(&mut cave, jetstream.next().expect("infinite stream")) {
-                break;
-            }
+/// The rocks of the puzzle, in the order they fall
+pub const ROCKS: &str = "\
+####
+
+.#.
+###
+.#.
+
+..#
+..#
+###
+
+#
+#
+#
+#
+
+##
+##";
+
+/// Rows a chamber stores before looking for rows no rock can reach anymore
+const PRUNE_ROWS: usize = 1024;
+
+/// Rows below the highest rock whose reachable cells make up the state of
+/// the tower, cells deeper down are taken to be out of reach of later rocks
+const SURFACE_ROWS: i64 = 64;
+
+/// Widest chamber, as each row is stored as the bits of a `u64`
+pub const MAX_WIDTH: i64 = u64::BITS as i64;
+
+pub struct Day17 {
+    /// Width of the chamber
+    pub width: i64,
+    /// Rocks in the order they fall, starting over after the last one
+    pub shapes: Vec<Shape>,
+    /// Rocks the runner's views drop, the tower view shows the chamber after
+    /// they all came to rest and the steps view every move they make
+    pub dropped: u64,
+    /// Rows from the top of the chamber the views draw
+    pub rows: usize,
+}
+
+impl Default for Day17 {
+    fn default() -> Self {
+        Self {
+            width: 7,
+            shapes: Shape::parse_all(ROCKS).expect("The puzzle's rocks parse"),
+            dropped: 10,
+            rows: 20,
         }
     }
-
-    cave.highest_rock.to_string()
 }
 
-pub fn process_part2(input: &str) -> String {
-    let (input, jets) = parse_input(input).unwrap();
-    assert!(input.is_empty());
-    let mut jetstream = jets.iter().cycle().intersperse(&Jet::Down).enumerate();
-    let mut cave = Cave {
-        width: 7,
-        rocks_count: 1_000_000_000_000,
-        next_rock_idx: 0,
-        rocks: HashSet::new(),
-        highest_rock: 0,
-    };
-    let cycle_length = jets.len() * 2;
-    let mut extra = 0;
-    let mut resting_positions: Vec<(u64, usize, u64, u64)> = Vec::new();
-    while let Some(mut rock) = cave.next() {
-        loop {
-            let (jet_count, jet) = jetstream.next().expect("infinite stream");
-            if rock.push(&mut cave, jet) {
-                if extra == 0 && rock.typ == RockType::Underscore {
-                    let tup = (
-                        rock.bottom_left.x,
-                        jet_count % cycle_length,
-                        cave.rocks_count,
-                        cave.highest_rock,
-                    );
-                    if let Some((_, _, previous_rock_count, previous_highest_rock)) =
-                        resting_positions.iter().find(|&&previous_tup| {
-                            previous_tup.0 == tup.0 && previous_tup.1 == tup.1
-                        })
-                    {
-                        // we've seen this kinda thing before!
-                        // it takes rockz to grow the stack by stack_size
-                        let rockz = previous_rock_count - cave.rocks_count;
-                        let stack_size = cave.highest_rock - previous_highest_rock;
-                        extra = (cave.rocks_count / rockz) * stack_size;
-                        cave.rocks_count %= rockz;
-                    };
-                    resting_positions.push(tup);
+impl Solution for Day17 {
+    type Input = Vec<Jet>;
+    type Output = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_all(input, parse_input)
+    }
+
+    fn part1(&self, jets: &Self::Input) -> Self::Output {
+        self.tower_height(jets, 2022)
+    }
+
+    fn part2(&self, jets: &Self::Input) -> Self::Output {
+        self.tower_height_cycled(jets, 1_000_000_000_000)
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let (width, shapes) = match key {
+            "width" => (parse_option(key, value)?, self.shapes.clone()),
+            "rocks" => {
+                let shapes = Shape::parse_all(value).map_err(|e| format!("{key}: {e}"))?;
+                (self.width, shapes)
+            }
+            "dropped" => {
+                self.dropped = parse_option(key, value)?;
+                return Ok(());
+            }
+            "rows" => {
+                self.rows = parse_option(key, value)?;
+                return Ok(());
+            }
+            _ => return Err(format!("unknown option '{key}'")),
+        };
+        if width > MAX_WIDTH {
+            return Err(format!("chambers are at most {MAX_WIDTH} wide"));
+        }
+        if let Some(shape) = shapes.iter().find(|shape| shape.width > width) {
+            return Err(format!(
+                "a rock {} wide doesn't fit in a chamber {width} wide",
+                shape.width
+            ));
+        }
+        self.width = width;
+        self.shapes = shapes;
+        Ok(())
+    }
+
+    fn show(&self, view: &str, jets: &Self::Input) -> Result<String, String> {
+        let mut cave = Cave::new(self, jets);
+        match view {
+            "tower" => {
+                for _ in 0..self.dropped {
+                    cave.drop_rock();
                 }
-                break;
+                Ok(cave.render(None, self.rows).to_string())
+            }
+            "steps" => {
+                let mut steps = vec![];
+                for _ in 0..self.dropped {
+                    cave.drop_rock_watched(|cave, rock, push| {
+                        let picture = cave.render(Some(rock), self.rows);
+                        steps.push(format!("{}:\n{picture}", describe_step(push)));
+                    });
+                }
+                Ok(steps.join("\n\n"))
             }
+            _ => Err(format!("unknown view '{view}'")),
         }
     }
+}
 
-    (cave.highest_rock + extra).to_string()
+/// Parses the value of a numeric option
+fn parse_option<T: FromStr>(key: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value '{value}' for {key}"))
+}
+
+pub fn process_part1(input: &str) -> Answer {
+    Day17::default().solve_part1(input).unwrap().into()
+}
+
+pub fn process_part2(input: &str) -> Answer {
+    Day17::default().solve_part2(input).unwrap().into()
+}
+
+impl Day17 {
+    /// Height of the tower after `rocks` rocks have come to rest
+    pub fn tower_height(&self, jets: &[Jet], rocks: u64) -> u64 {
+        let mut cave = Cave::new(self, jets);
+        for _ in 0..rocks {
+            cave.drop_rock();
+        }
+        cave.highest_rock as u64
+    }
+
+    /// Like [`Day17::tower_height`], skipping ahead once the top of the tower
+    /// repeats itself
+    pub fn tower_height_cycled(&self, jets: &[Jet], rocks: u64) -> u64 {
+        let mut cave = Cave::new(self, jets);
+        // height of the tower after each rock
+        let mut heights = vec![0];
+        let states = iter::repeat_with(|| {
+            let state = cave.state();
+            cave.drop_rock();
+            heights.push(cave.highest_rock as u64);
+            state
+        });
+        let Some(cycle) = find_cycle(states.take(rocks as usize)) else {
+            return heights[rocks as usize];
+        };
+        let (equivalent, cycles) = cycle.reduce(rocks);
+        let growth = heights[cycle.start + cycle.length] - heights[cycle.start];
+        heights[equivalent] + cycles * growth
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
-enum Jet {
+pub enum Jet {
     Left,
     Right,
     Down, // gravity implemented as a downward jet
 }
 
 impl Jet {
+    /// Where the jet pushes a rock, `y` grows upwards in the chamber
+    fn offset(&self) -> Point2 {
+        match self {
+            Jet::Left => Point2::new(-1, 0),
+            Jet::Right => Point2::new(1, 0),
+            Jet::Down => Point2::new(0, -1),
+        }
+    }
+
     fn parse(input: &str) -> IResult<&str, Self> {
         let (input, c) = one_of("<>")(input)?;
         let jet = match c {
@@ -94,267 +209,314 @@ impl Jet {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Point {
-    x: u64,
-    y: u64,
+/// A rock as the points it covers from its bottom left corner, `y` grows upwards
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shape {
+    points: Vec<Point2>,
+    width: i64,
+    height: i64,
 }
 
-impl Point {
-    fn new(x: u64, y: u64) -> Self {
-        Self { x, y }
-    }
-
-    fn left_one(&self) -> Self {
-        Self {
-            x: self.x - 1,
-            y: self.y,
+impl Shape {
+    /// Parses rocks drawn like in the puzzle, `#` for rock and `.` for air,
+    /// with a blank line between rocks
+    pub fn parse_all(input: &str) -> Result<Vec<Self>, ParseError> {
+        let input = normalize(input);
+        let input = input.as_ref();
+        let mut shapes = vec![];
+        let mut rows = vec![];
+        let mut offset = 0;
+        for line in input.split('\n').chain([""]) {
+            if line.is_empty() {
+                if !rows.is_empty() {
+                    shapes.push(Self::from_rows(&rows));
+                    rows.clear();
+                }
+            } else {
+                let row = line
+                    .char_indices()
+                    .map(|(i, c)| match c {
+                        '#' => Ok(true),
+                        '.' => Ok(false),
+                        _ => Err(ParseError::at(input, &input[offset + i..], "'#' or '.'")),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                if !row.contains(&true) && rows.is_empty() {
+                    // rocks start with their top row
+                    return Err(ParseError::at(input, &input[offset..], "a row with a '#'"));
+                }
+                rows.push(row);
+            }
+            offset += line.len() + 1;
         }
-    }
-
-    fn right_one(&self) -> Self {
-        Self {
-            x: self.x + 1,
-            y: self.y,
+        if shapes.is_empty() {
+            return Err(ParseError::at(input, "", "a rock"));
         }
+        Ok(shapes)
     }
 
-    fn down_one(&self) -> Self {
+    /// The rock drawn in `rows`, top row first, which has some rock
+    fn from_rows(rows: &[Vec<bool>]) -> Self {
+        let top = rows.len() as i64 - 1;
+        let points = rows
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|&(_, &rock)| rock)
+                    .map(move |(x, _)| Point2::new(x as i64, top - y as i64))
+            })
+            .collect::<Vec<_>>();
+        let bounds = Rect::from_points(points.iter().copied()).expect("The top row has rock");
         Self {
-            x: self.x,
-            y: self.y - 1,
+            points: points.into_iter().map(|p| p - bounds.min).collect(),
+            width: bounds.width() as i64,
+            height: bounds.height() as i64,
         }
     }
 }
 
-impl std::ops::Add for Point {
-    type Output = Self;
+#[derive(Debug)]
+struct Rock<'a> {
+    shape: &'a Shape,
+    bottom_left: Point2,
+}
 
-    fn add(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
+impl<'a> Rock<'a> {
+    /// Appears with its left edge two units away from the left wall, or as far
+    /// as the chamber allows, and its bottom three units above the highest rock
+    fn new(shape: &'a Shape, cave: &Cave) -> Self {
+        let bottom_left = Point2::new(2.min(cave.width - shape.width), cave.highest_rock + 4);
+        Self { shape, bottom_left }
     }
-}
 
-impl std::ops::AddAssign for Point {
-    fn add_assign(&mut self, rhs: Self) {
-        self.x += rhs.x;
-        self.y += rhs.y;
+    fn points(&self) -> impl Iterator<Item = Point2> + '_ {
+        self.shape.points.iter().map(|&p| p + self.bottom_left)
+    }
+
+    /// true if neither the walls, the floor nor a rock in `cave` are in the
+    /// way of moving by `offset`
+    fn can_move(&self, cave: &Cave, offset: Point2) -> bool {
+        self.points()
+            .map(|p| p + offset)
+            .all(|p| (0..cave.width).contains(&p.x) && !cave.is_rock(p))
+    }
+
+    /// Moves the rock the way `jet` blows unless something is in the way,
+    /// returns true if it moved
+    fn push(&mut self, cave: &Cave, jet: &Jet) -> bool {
+        let moved = self.can_move(cave, jet.offset());
+        if moved {
+            self.bottom_left += jet.offset();
+        }
+        moved
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-enum RockType {
-    Underscore,
-    // ####
-    Plus,
-    // .#.
-    // ###
-    // .#.
-    ReverseL,
-    // ..#
-    // ..#
-    // ###
-    I,
-    // #
-    // #
-    // #
-    // #
-    O,
-    // ##
-    // ##
+struct Cave<'a> {
+    width: i64,
+    shapes: &'a [Shape],
+    jets: &'a [Jet],
+    next_rock_idx: usize,
+    next_jet_idx: usize,
+    /// Rock in every row from `lowest_row` up as bits, bit `x` for column `x`
+    rows: VecDeque<u64>,
+    /// Row the first of `rows` is, the floor or a row full of rock with all
+    /// rows below it forgotten as no rock can get there anymore
+    lowest_row: i64,
+    /// Number of `rows` at which to look for more rows to forget
+    prune_at: usize,
+    highest_rock: i64,
 }
 
-impl RockType {
-    fn height(&self) -> u64 {
-        match self {
-            Self::Underscore => 1,
-            Self::Plus => 3,
-            Self::ReverseL => 3,
-            Self::I => 4,
-            Self::O => 2,
+impl<'a> Cave<'a> {
+    /// Empty chamber the rocks of `day` fall into, pushed around by `jets`
+    fn new(day: &'a Day17, jets: &'a [Jet]) -> Self {
+        Self {
+            width: day.width,
+            shapes: &day.shapes,
+            jets,
+            next_rock_idx: 0,
+            next_jet_idx: 0,
+            // the floor
+            rows: VecDeque::from([full_row(day.width)]),
+            lowest_row: 0,
+            prune_at: PRUNE_ROWS,
+            highest_rock: 0,
         }
     }
 
-    fn width(&self) -> u64 {
-        match self {
-            Self::Underscore => 4,
-            Self::Plus => 3,
-            Self::ReverseL => 3,
-            Self::I => 1,
-            Self::O => 2,
+    /// true for rock, the floor and rows below a full one
+    fn is_rock(&self, point: Point2) -> bool {
+        if point.y < self.lowest_row {
+            return true;
         }
+        let row = self.rows.get((point.y - self.lowest_row) as usize);
+        row.is_some_and(|row| row & 1 << point.x != 0)
     }
 
-    fn points(&self) -> impl Iterator<Item = Point> {
-        fn points_iter(points: &[Point]) -> impl Iterator<Item = Point> + '_ {
-            points.iter().cloned()
+    /// Adds `rock`, which has come to rest, to the tower
+    fn settle(&mut self, rock: &Rock) {
+        for p in rock.points() {
+            self.highest_rock = self.highest_rock.max(p.y);
+            let row = (p.y - self.lowest_row) as usize;
+            if row >= self.rows.len() {
+                self.rows.resize(row + 1, 0);
+            }
+            self.rows[row] |= 1 << p.x;
         }
-        match self {
-            RockType::Underscore => points_iter(&[
-                Point { x: 0, y: 0 },
-                Point { x: 1, y: 0 },
-                Point { x: 2, y: 0 },
-                Point { x: 3, y: 0 },
-            ]),
-            RockType::Plus => points_iter(&[
-                Point { x: 1, y: 2 },
-                Point { x: 2, y: 1 },
-                Point { x: 1, y: 1 },
-                Point { x: 0, y: 1 },
-                Point { x: 1, y: 0 },
-            ]),
-            RockType::ReverseL => points_iter(&[
-                Point { x: 0, y: 0 },
-                Point { x: 1, y: 0 },
-                Point { x: 2, y: 0 },
-                Point { x: 2, y: 1 },
-                Point { x: 2, y: 2 },
-            ]),
-            RockType::I => points_iter(&[
-                Point { x: 0, y: 0 },
-                Point { x: 0, y: 1 },
-                Point { x: 0, y: 2 },
-                Point { x: 0, y: 3 },
-            ]),
-            RockType::O => points_iter(&[
-                Point { x: 0, y: 1 },
-                Point { x: 1, y: 1 },
-                Point { x: 0, y: 0 },
-                Point { x: 1, y: 0 },
-            ]),
+        let rows = rock.bottom_left.y..rock.bottom_left.y + rock.shape.height;
+        let full = full_row(self.width);
+        if let Some(y) = rows
+            .rev()
+            .find(|&y| self.rows[(y - self.lowest_row) as usize] == full)
+        {
+            self.forget_below(y);
         }
-    }
-}
-
-impl TryFrom<u8> for RockType {
-    type Error = InvalidEnumValueError;
-
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(RockType::Underscore),
-            1 => Ok(RockType::Plus),
-            2 => Ok(RockType::ReverseL),
-            3 => Ok(RockType::I),
-            4 => Ok(RockType::O),
-            x => Err(InvalidEnumValueError(x)),
+        if self.rows.len() > self.prune_at {
+            self.forget_unreachable();
+            self.prune_at = PRUNE_ROWS.max(2 * self.rows.len());
         }
     }
-}
-
-#[derive(Debug)]
-struct InvalidEnumValueError(u8);
 
-impl std::fmt::Display for InvalidEnumValueError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Invalid value for Rock: {}", self.0)
+    /// Drops the rows below `y`, which must be stored
+    fn forget_below(&mut self, y: i64) {
+        self.rows.drain(..(y - self.lowest_row) as usize);
+        self.lowest_row = y;
     }
-}
-
-impl std::error::Error for InvalidEnumValueError {}
 
-#[derive(Debug)]
-
-struct Rock {
-    typ: RockType,
-    bottom_left: Point,
-}
+    /// Drops the rows no rock can reach anymore, which are below the lowest
+    /// free cell connected to the top of the tower, keeping the one below it
+    /// that rocks can land on
+    fn forget_unreachable(&mut self) {
+        let lowest = self
+            .reachable(i64::MAX)
+            .map(|p| p.y)
+            .min()
+            .expect("The top is free");
+        if lowest - 1 > self.lowest_row {
+            self.forget_below(lowest - 1);
+        }
+    }
 
-impl Rock {
-    fn new(type_idx: u8, highest_rock: u64) -> Self {
-        let typ: RockType = type_idx.try_into().expect("Invalid type_idx");
-        let bottom_left = Point::new(2, highest_rock + 4);
-        Self { typ, bottom_left }
+    /// Free cells a rock could get to from above the tower, going at most
+    /// `depth` rows below the highest rock
+    fn reachable(&self, depth: i64) -> impl Iterator<Item = Point2> {
+        let above = self.highest_rock + 1;
+        let deepest = self.highest_rock.saturating_sub(depth);
+        let starts = (0..self.width).map(|x| Point2::new(x, above));
+        let free = bfs_distances(starts, |&p| {
+            p.neighbours4()
+                .filter(|&n| {
+                    (0..self.width).contains(&n.x)
+                        && (deepest..=above).contains(&n.y)
+                        && !self.is_rock(n)
+                })
+                .collect::<Vec<_>>()
+        });
+        free.into_keys()
     }
 
-    fn points(&self) -> impl Iterator<Item = Point> + '_ {
-        self.typ.points().map(|p| p + self.bottom_left)
+    /// Lets the next rock fall until it comes to rest
+    fn drop_rock(&mut self) {
+        self.drop_rock_watched(|_, _, _| {});
     }
 
-    /// returns true if rock has landed
-    fn push(&mut self, cave: &mut Cave, jet: &Jet) -> bool {
-        match jet {
-            Jet::Down
-                if self.bottom_left.y > 1
-                    && self.points().all(|p| !cave.rocks.contains(&p.down_one())) =>
-            {
-                self.bottom_left = self.bottom_left.down_one();
-            }
-            Jet::Down => {
-                cave.highest_rock = cave
-                    .highest_rock
-                    .max(self.bottom_left.y + self.typ.height() - 1);
-                cave.rocks.extend(self.points());
-                return true;
+    /// Like [`Cave::drop_rock`], calling `watch` when the rock appears, with
+    /// `None`, and after every push with the jet and whether the rock moved
+    fn drop_rock_watched(&mut self, mut watch: impl FnMut(&Self, &Rock, Option<(Jet, bool)>)) {
+        let mut rock = Rock::new(&self.shapes[self.next_rock_idx], self);
+        self.next_rock_idx = (self.next_rock_idx + 1) % self.shapes.len();
+        watch(self, &rock, None);
+        loop {
+            let jet = self.jets[self.next_jet_idx];
+            self.next_jet_idx = (self.next_jet_idx + 1) % self.jets.len();
+            let moved = rock.push(self, &jet);
+            watch(self, &rock, Some((jet, moved)));
+            let fell = rock.push(self, &Jet::Down);
+            if !fell {
+                self.settle(&rock);
             }
-
-            Jet::Left
-                if self.bottom_left.x > 0
-                    && self.points().all(|p| !cave.rocks.contains(&p.left_one())) =>
-            {
-                self.bottom_left = self.bottom_left.left_one();
+            watch(self, &rock, Some((Jet::Down, fell)));
+            if !fell {
+                break;
             }
+        }
+    }
 
-            Jet::Right
-                if self.bottom_left.x + self.typ.width() < cave.width as u64
-                    && self.points().all(|p| !cave.rocks.contains(&p.right_one())) =>
-            {
-                self.bottom_left = self.bottom_left.right_one();
+    /// The free cells rocks can get to in the top [`SURFACE_ROWS`] rows of
+    /// the tower as bits, the highest rock's row first
+    fn surface(&self) -> Vec<u64> {
+        let mut surface = vec![0; SURFACE_ROWS as usize + 1];
+        for p in self.reachable(SURFACE_ROWS) {
+            if p.y <= self.highest_rock {
+                surface[(self.highest_rock - p.y) as usize] |= 1 << p.x;
             }
-            _ => {}
         }
-        false
+        surface
+    }
+
+    /// Everything that decides how the tower grows from here: the next rock,
+    /// the next jet and the surface of the tower
+    fn state(&self) -> (usize, usize, Vec<u64>) {
+        (self.next_rock_idx, self.next_jet_idx, self.surface())
     }
 }
 
-struct Cave {
-    width: u8,
-    rocks_count: u64,
-    next_rock_idx: u8,
-    rocks: HashSet<Point>,
-    highest_rock: u64,
+fn parse_input(input: &str) -> IResult<&str, Vec<Jet>> {
+    many1(Jet::parse)(input)
 }
 
-impl Iterator for Cave {
-    type Item = Rock;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.rocks_count == 0 {
-            None
-        } else {
-            let next_rock = Rock::new(self.next_rock_idx, self.highest_rock);
-            self.next_rock_idx = (self.next_rock_idx + 1) % 5;
-            self.rocks_count -= 1;
-            Some(next_rock)
+impl Cave<'_> {
+    /// The top `rows` rows of the chamber like the puzzle draws it, with the
+    /// `falling` rock as `@`, the floor shows once it is among them and rows
+    /// that were forgotten show as a single row of `~`
+    fn render(&self, falling: Option<&Rock>, rows: usize) -> Grid<char> {
+        let falling_points = falling
+            .into_iter()
+            .flat_map(|rock| rock.points())
+            .collect::<HashSet<_>>();
+        let top = falling_points
+            .iter()
+            .map(|p| p.y)
+            .fold(self.highest_rock, i64::max);
+        let bottom = (top - rows as i64 + 1).max(self.lowest_row - 1).max(0);
+        let wall = self.width as usize + 1;
+        let mut picture = Grid::new(wall + 1, (top - bottom + 1) as usize, '.');
+        for cell in picture.points() {
+            let point = Point2::new(cell.x as i64 - 1, top - cell.y as i64);
+            let side = cell.x == 0 || cell.x == wall;
+            picture[cell] = match (point.y, side) {
+                (0, true) => '+',
+                (0, false) => '-',
+                (_, true) => '|',
+                (y, false) if y < self.lowest_row => '~',
+                _ if self.is_rock(point) => '#',
+                _ if falling_points.contains(&point) => '@',
+                _ => '.',
+            };
         }
+        picture
     }
 }
 
-fn parse_input(input: &str) -> IResult<&str, Vec<Jet>> {
-    many1(Jet::parse)(input)
+/// Row with rock in every column of a chamber `width` wide
+fn full_row(width: i64) -> u64 {
+    u64::MAX >> (MAX_WIDTH - width)
 }
 
-#[allow(dead_code)]
-fn print_cave(cave: &Cave, rock: &Rock) {
-    let rocks = rock.points().collect::<HashSet<_>>();
-    (1..=(cave.highest_rock + 7)).rev().for_each(|y| {
-        print!("|");
-        (0..7).for_each(|x| {
-            let point = Point::new(x, y);
-            if cave.rocks.contains(&point) {
-                print!("#");
-            } else if rocks.contains(&point) {
-                print!("@");
-            } else {
-                print!(".");
-            }
-        });
-        println!("|");
-    });
-    println!("+-------+");
-    println!();
+/// What happened to a rock, in the words of the puzzle
+fn describe_step(push: Option<(Jet, bool)>) -> &'static str {
+    match push {
+        None => "A new rock begins falling",
+        Some((Jet::Left, true)) => "Jet of gas pushes rock left",
+        Some((Jet::Left, false)) => "Jet of gas pushes rock left, but nothing happens",
+        Some((Jet::Right, true)) => "Jet of gas pushes rock right",
+        Some((Jet::Right, false)) => "Jet of gas pushes rock right, but nothing happens",
+        Some((Jet::Down, true)) => "Rock falls 1 unit",
+        Some((Jet::Down, false)) => "Rock falls 1 unit, causing it to come to rest",
+    }
 }
 
 #[cfg(test)]
@@ -366,13 +528,238 @@ mod tests {
     fn part1() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part1(&input);
-        assert_eq!(result, "3068");
+        assert_eq!(result, Answer::Integer(3068));
     }
 
     #[test]
     fn part2() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part2(&input);
-        assert_eq!(result, "1514285714288");
+        assert_eq!(result, Answer::Integer(1514285714288));
+    }
+
+    #[test]
+    fn shapes() {
+        let shapes = Shape::parse_all(ROCKS).unwrap();
+        let sizes = shapes
+            .iter()
+            .map(|s| (s.width, s.height, s.points.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sizes,
+            [(4, 1, 4), (3, 3, 5), (3, 3, 5), (1, 4, 4), (2, 2, 4)]
+        );
+        // the bottom row of the L is the long one
+        assert!(shapes[2].points.contains(&Point2::new(0, 0)));
+        assert!(shapes[2].points.contains(&Point2::new(2, 2)));
+
+        let error = Shape::parse_all("##\n\n.#\n#x").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 2));
+        assert!(Shape::parse_all("\n\n").is_err());
+        assert_eq!(Shape::parse_all("#\r\n\r\n##\r\n").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn chamber_width() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        let jets = Day17::default().load(&input).unwrap();
+
+        // rocks as wide as the chamber stack up a row each
+        let mut day = Day17::default();
+        day.configure("width", "4").unwrap();
+        day.configure("rocks", "####").unwrap();
+        assert_eq!(day.tower_height(&jets, 100), 100);
+
+        // a wider chamber leaves room for rocks to slide past each other
+        day.configure("width", "8").unwrap();
+        day.configure("rocks", "#\n#").unwrap();
+        assert!(day.tower_height(&jets, 100) < 200);
+
+        assert!(day.configure("width", "0").is_err());
+        day.configure("rocks", ROCKS).unwrap();
+        assert!(day.configure("width", "3").is_err());
+        assert_eq!(day.width, 8);
+    }
+
+    /// Height of the tower after each rock, simulating every one of them
+    fn brute_force(day: &Day17, jets: &[Jet], rocks: usize) -> Vec<u64> {
+        let mut cave = Cave::new(day, jets);
+        let mut heights = vec![0];
+        for _ in 0..rocks {
+            cave.drop_rock();
+            heights.push(cave.highest_rock as u64);
+        }
+        heights
+    }
+
+    #[test]
+    fn cycle_matches_brute_force() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        let jets = Day17::default().load(&input).unwrap();
+        let mut custom = Day17::default();
+        custom.configure("width", "5").unwrap();
+        custom.configure("rocks", "##\n\n#\n#\n\n.#\n##").unwrap();
+        let mut wide = Day17::default();
+        wide.configure("width", "11").unwrap();
+        // a single jet
+        let gusty = [Jet::Left];
+
+        for (day, jets) in [
+            (&Day17::default(), &jets[..]),
+            (&custom, &jets),
+            (&wide, &jets),
+            (&Day17::default(), &gusty),
+        ] {
+            // a cycle that comes back before the end, so skipping ahead is tested
+            let mut cave = Cave::new(day, jets);
+            let states = iter::repeat_with(|| {
+                let state = cave.state();
+                cave.drop_rock();
+                state
+            });
+            let cycle = find_cycle(states.take(3000)).unwrap();
+            assert!(cycle.start + cycle.length < 2000);
+
+            let heights = brute_force(day, jets, 3000);
+            for rocks in (0..3000).step_by(101) {
+                assert_eq!(
+                    day.tower_height_cycled(jets, rocks as u64),
+                    heights[rocks],
+                    "after {rocks} rocks"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn trillion_rocks() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        let jets = Day17::default().load(&input).unwrap();
+        let mut wide = Day17::default();
+        wide.configure("width", "11").unwrap();
+        // never reaches the right of the chamber, which stays open down to the
+        // floor, and every 5 rocks stack up the same way against the left wall
+        let gusty = [Jet::Left];
+        let day = Day17::default();
+        let trillion = 1_000_000_000_000;
+        assert_eq!(
+            day.tower_height_cycled(&gusty, trillion),
+            trillion / 5 * day.tower_height(&gusty, 5)
+        );
+        let height = wide.tower_height_cycled(&jets, trillion);
+        assert!(height > wide.tower_height(&jets, 5000));
+    }
+
+    #[test]
+    fn bounded_memory() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        let day = Day17::default();
+        let jets = day.load(&input).unwrap();
+        // the example's tower never has a full row, so only what rocks can't
+        // reach anymore is forgotten
+        let mut cave = Cave::new(&day, &jets);
+        let mut most_rows = 0;
+        for _ in 0..200_000 {
+            cave.drop_rock();
+            most_rows = most_rows.max(cave.rows.len());
+        }
+        assert!(most_rows < 2 * PRUNE_ROWS);
+        assert_eq!(
+            cave.highest_rock as u64,
+            day.tower_height_cycled(&jets, 200_000)
+        );
+
+        let mut day = Day17::default();
+        day.configure("width", "4").unwrap();
+        day.configure("rocks", "####\n\n#.\n##").unwrap();
+        let mut cave = Cave::new(&day, &jets);
+        for _ in 0..1000 {
+            cave.drop_rock();
+        }
+        // every flat rock fills a whole row
+        assert!(cave.rows.len() <= 4);
+        assert!(day.configure("width", "65").is_err());
+    }
+
+    #[test]
+    fn render_tower() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        let jets = Day17::default().load(&input).unwrap();
+        let tower = Day17::default().show("tower", &jets).unwrap();
+        let expected = "\
+|....#..|
+|....#..|
+|....##.|
+|##..##.|
+|######.|
+|.###...|
+|..#....|
+|.####..|
+|....##.|
+|....##.|
+|....#..|
+|..#.#..|
+|..#.#..|
+|#####..|
+|..###..|
+|...#...|
+|..####.|
++-------+";
+        assert_eq!(tower, expected);
+
+        let mut day = Day17::default();
+        day.configure("rows", "3").unwrap();
+        let top = day.show("tower", &jets).unwrap();
+        assert_eq!(top, expected.lines().take(3).collect::<Vec<_>>().join("\n"));
+    }
+
+    #[test]
+    fn render_forgotten_rows() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        let jets = Day17::default().load(&input).unwrap();
+        let mut day = Day17::default();
+        day.configure("dropped", "3000").unwrap();
+        day.configure("rows", "5000").unwrap();
+        let tower = day.show("tower", &jets).unwrap();
+        let lines = tower.lines().collect::<Vec<_>>();
+        assert_eq!(lines.last(), Some(&"|~~~~~~~|"));
+        assert!(lines.len() < 1100);
+        assert!(!lines[..lines.len() - 1].contains(&"|#######|"));
+
+        day.configure("rows", "20").unwrap();
+        let top = day.show("tower", &jets).unwrap();
+        assert_eq!(top, lines[..20].join("\n"));
+    }
+
+    #[test]
+    fn render_steps() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        let jets = Day17::default().load(&input).unwrap();
+        let mut day = Day17::default();
+        day.configure("dropped", "1").unwrap();
+        let steps = day.show("steps", &jets).unwrap();
+        let steps = steps.split("\n\n").collect::<Vec<_>>();
+        assert_eq!(steps.len(), 9);
+        assert_eq!(
+            steps[0],
+            "A new rock begins falling:\n|..@@@@.|\n|.......|\n|.......|\n|.......|\n+-------+"
+        );
+        assert_eq!(
+            steps[3],
+            "Jet of gas pushes rock right, but nothing happens:\n|...@@@@|\n|.......|\n|.......|\n+-------+"
+        );
+        assert_eq!(
+            steps[8],
+            "Rock falls 1 unit, causing it to come to rest:\n|..####.|\n+-------+"
+        );
+        assert!(day.show("nothing", &jets).is_err());
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Integer(3068));
+        }
     }
 }
@@ -1,184 +1,77 @@
-use std::{
-    collections::{HashSet, VecDeque},
-    ops::{Add, AddAssign, RangeInclusive, Sub, SubAssign},
-};
+use std::collections::HashSet;
 
+use common::{bfs_distances, parse_all, Answer, Cuboid, ParseError, Point3, Solution};
 use nom::{
     character::complete::char as nomchar,
-    character::complete::{i8 as nomi8, newline},
+    character::complete::{i64 as nomi64, newline},
     multi::separated_list1,
     IResult,
 };
 
-pub fn process_part1(input: &str) -> String {
-    let (input, points_vec) = parse_input(input).unwrap();
-    assert!(input.is_empty());
-    let points = points_vec.into_iter().collect::<HashSet<_>>();
-    let exposed_faces = points
-        .iter()
-        .map(|p| p.neighbours().filter(|n| !points.contains(n)).count())
-        .sum::<usize>();
-    exposed_faces.to_string()
-}
-
-pub fn process_part2(input: &str) -> String {
-    let (input, points_vec) = parse_input(input).unwrap();
-    assert!(input.is_empty());
-    let (mut min, mut max) = (Point::MAX, Point::MIN);
-    let points = points_vec
-        .into_iter()
-        .inspect(|p| {
-            min = min.min(p);
-            max = max.max(p);
-        })
-        .collect::<HashSet<_>>();
-    let one = Point { x: 1, y: 1, z: 1 };
-    max += one;
-    min -= one;
-    let range = PointRange::new(min, max);
-    let mut outside_faces = 0;
-    let mut visited = HashSet::new();
-    let mut queue = VecDeque::new();
-    queue.push_back(max);
-    while let Some(point) = queue.pop_front() {
-        point.neighbours_in_range(&range).for_each(|n| {
-            if visited.contains(&n) {
-                return;
-            }
-            if points.contains(&n) {
-                outside_faces += 1;
-            } else {
-                queue.push_back(n);
-                visited.insert(n);
-            }
-        })
-    }
-    outside_faces.to_string()
-}
-
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
-struct Point {
-    x: i8,
-    y: i8,
-    z: i8,
-}
-
-impl Point {
-    const NEIGHBOURS: [Self; 6] = [
-        Self { x: -1, y: 0, z: 0 },
-        Self { x: 1, y: 0, z: 0 },
-        Self { x: 0, y: -1, z: 0 },
-        Self { x: 0, y: 1, z: 0 },
-        Self { x: 0, y: 0, z: -1 },
-        Self { x: 0, y: 0, z: 1 },
-    ];
-
-    const MAX: Self = Self {
-        x: i8::MAX,
-        y: i8::MAX,
-        z: i8::MAX,
-    };
-    const MIN: Self = Self {
-        x: i8::MIN,
-        y: i8::MIN,
-        z: i8::MIN,
-    };
+pub struct Day18;
 
-    fn parse(input: &str) -> IResult<&str, Self> {
-        let (input, x) = nomi8(input)?;
-        let (input, _) = nomchar(',')(input)?;
-        let (input, y) = nomi8(input)?;
-        let (input, _) = nomchar(',')(input)?;
-        let (input, z) = nomi8(input)?;
-        Ok((input, Self { x, y, z }))
-    }
-
-    fn neighbours(&self) -> impl Iterator<Item = Self> + '_ {
-        Self::NEIGHBOURS.iter().cloned().map(|n| *self + n)
-    }
+impl Solution for Day18 {
+    type Input = Vec<Point3>;
+    type Output = usize;
 
-    fn neighbours_in_range<'a>(&'a self, range: &'a PointRange) -> impl Iterator<Item = Self> + 'a {
-        self.neighbours().filter(|n| range.contains(n))
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_all(input, parse_input)
     }
 
-    fn max(&self, rhs: &Self) -> Self {
-        Self {
-            x: self.x.max(rhs.x),
-            y: self.y.max(rhs.y),
-            z: self.z.max(rhs.z),
-        }
-    }
-
-    fn min(&self, rhs: &Self) -> Self {
-        Self {
-            x: self.x.min(rhs.x),
-            y: self.y.min(rhs.y),
-            z: self.z.min(rhs.z),
-        }
+    fn part1(&self, points: &Self::Input) -> Self::Output {
+        exposed_faces(points)
     }
-}
-
-impl Add for Point {
-    type Output = Self;
 
-    fn add(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-            z: self.z + rhs.z,
-        }
+    fn part2(&self, points: &Self::Input) -> Self::Output {
+        outside_faces(points)
     }
 }
 
-impl AddAssign for Point {
-    fn add_assign(&mut self, rhs: Self) {
-        self.x += rhs.x;
-        self.y += rhs.y;
-        self.z += rhs.z;
-    }
+pub fn process_part1(input: &str) -> Answer {
+    Day18.solve_part1(input).unwrap().into()
 }
 
-impl Sub for Point {
-    type Output = Self;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-            z: self.z - rhs.z,
-        }
-    }
+pub fn process_part2(input: &str) -> Answer {
+    Day18.solve_part2(input).unwrap().into()
 }
 
-impl SubAssign for Point {
-    fn sub_assign(&mut self, rhs: Self) {
-        self.x -= rhs.x;
-        self.y -= rhs.y;
-        self.z -= rhs.z;
-    }
+fn exposed_faces(points: &[Point3]) -> usize {
+    let points = points.iter().copied().collect::<HashSet<_>>();
+    points
+        .iter()
+        .map(|p| p.neighbours6().filter(|n| !points.contains(n)).count())
+        .sum::<usize>()
 }
 
-struct PointRange {
-    x: RangeInclusive<i8>,
-    y: RangeInclusive<i8>,
-    z: RangeInclusive<i8>,
+fn outside_faces(points: &[Point3]) -> usize {
+    let Some(bounds) = Cuboid::from_points(points.iter().copied()) else {
+        return 0;
+    };
+    // leave room to flow around the droplet
+    let range = bounds.grow(1);
+    let points = points.iter().copied().collect::<HashSet<_>>();
+    let outside = bfs_distances([range.max], |point| {
+        point
+            .neighbours6()
+            .filter(|&n| range.contains(n) && !points.contains(&n))
+    });
+    outside
+        .keys()
+        .map(|p| p.neighbours6().filter(|n| points.contains(n)).count())
+        .sum()
 }
 
-impl PointRange {
-    fn new(min: Point, max: Point) -> Self {
-        Self {
-            x: min.x..=max.x,
-            y: min.y..=max.y,
-            z: min.z..=max.z,
-        }
-    }
-    fn contains(&self, point: &Point) -> bool {
-        self.x.contains(&point.x) && self.y.contains(&point.y) && self.z.contains(&point.z)
-    }
+fn parse_point(input: &str) -> IResult<&str, Point3> {
+    let (input, x) = nomi64(input)?;
+    let (input, _) = nomchar(',')(input)?;
+    let (input, y) = nomi64(input)?;
+    let (input, _) = nomchar(',')(input)?;
+    let (input, z) = nomi64(input)?;
+    Ok((input, Point3::new(x, y, z)))
 }
 
-fn parse_input(input: &str) -> IResult<&str, Vec<Point>> {
-    let (input, lines) = separated_list1(newline, Point::parse)(input)?;
+fn parse_input(input: &str) -> IResult<&str, Vec<Point3>> {
+    let (input, lines) = separated_list1(newline, parse_point)(input)?;
     Ok((input, lines))
 }
 
@@ -191,13 +84,21 @@ mod tests {
     fn part1() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part1(&input);
-        assert_eq!(result, "64");
+        assert_eq!(result, Answer::Integer(64));
     }
 
     #[test]
     fn part2() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part2(&input);
-        assert_eq!(result, "58");
+        assert_eq!(result, Answer::Integer(58));
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Integer(64));
+        }
     }
 }
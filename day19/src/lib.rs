@@ -1,163 +1,335 @@
-use std::{collections::HashMap, vec};
+use std::{
+    collections::HashMap,
+    num::NonZeroUsize,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread, vec,
+};
 
+use common::{parse_all, Answer, ParseError, Solution};
 use nom::{
-    branch::alt,
     bytes::complete::tag,
-    character::complete::{newline, space1, u8 as nomu8},
-    multi::separated_list1,
-    sequence::pair,
+    character::complete::{alpha1, multispace1, space1, u16 as nomu16, u32 as nomu32},
+    multi::{many1, separated_list1},
+    sequence::{delimited, pair, preceded, separated_pair, terminated},
     IResult,
 };
 
-const ORE: usize = 0;
-const CLAY: usize = 1;
-const OBSIDIAN: usize = 2;
-const GEODE: usize = 3;
-
-pub fn process_part1(input: &str) -> String {
-    let (input, blueprints) = parse_input(input).unwrap();
-    assert!(input.is_empty());
-    let quality = blueprints.iter().map(|b| b.quality_level(24)).sum::<u16>();
-    quality.to_string()
-}
-
-pub fn process_part2(input: &str) -> String {
-    let (input, blueprints) = parse_input(input).unwrap();
-    assert!(input.is_empty());
-    let mut cache = HashMap::new();
-    let max = blueprints
-        .iter()
-        .take(3)
-        .map(|b| {
-            cache.clear();
-            dfs(b, State::new(32), &mut cache) as u64
-        })
-        .product::<u64>();
-    max.to_string()
+/// Most kinds of resources a blueprint can have
+pub const MAX_RESOURCES: usize = 8;
+
+pub struct Day19 {
+    /// Threads the blueprints are shared between
+    pub threads: NonZeroUsize,
+    pub search: Search,
+    /// Resource to collect as much of as possible
+    pub goal: String,
+}
+
+/// How to find the most geodes a blueprint can open, `aoc bench --day 19
+/// --set search=<search>` times each
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Search {
+    /// Every build order, remembering the states seen before
+    Memoised,
+    /// Build orders that could still beat the best one found so far
+    BranchAndBound,
+}
+
+impl Default for Day19 {
+    /// As many threads as the machine runs at once
+    fn default() -> Self {
+        Self {
+            threads: thread::available_parallelism().unwrap_or(NonZeroUsize::MIN),
+            search: Search::BranchAndBound,
+            goal: "geode".to_string(),
+        }
+    }
+}
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+    type Output = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_all(input, parse_input)?
+            .into_iter()
+            .map(|(rest, id, recipes)| Blueprint::new(input, rest, id, recipes, &self.goal))
+            .collect()
+    }
+
+    fn part1(&self, blueprints: &Self::Input) -> Self::Output {
+        self.most_geodes(blueprints, 24)
+            .into_iter()
+            .zip(blueprints)
+            .map(|(geodes, b)| geodes as u64 * b.id as u64)
+            .sum::<u64>()
+    }
+
+    fn part2(&self, blueprints: &Self::Input) -> Self::Output {
+        let blueprints = &blueprints[..blueprints.len().min(3)];
+        self.most_geodes(blueprints, 32)
+            .into_iter()
+            .map(|geodes| geodes as u64)
+            .product::<u64>()
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "threads" => {
+                self.threads = value
+                    .parse()
+                    .map_err(|_| format!("invalid value '{value}' for {key}"))?;
+                Ok(())
+            }
+            "search" => {
+                self.search = match value {
+                    "memoised" => Search::Memoised,
+                    "branch-and-bound" => Search::BranchAndBound,
+                    _ => return Err(format!("invalid value '{value}' for {key}")),
+                };
+                Ok(())
+            }
+            "goal" => {
+                self.goal = value.to_string();
+                Ok(())
+            }
+            _ => Err(format!("unknown option '{key}'")),
+        }
+    }
+}
+
+impl Day19 {
+    /// Most geodes each of `blueprints` can open in `minutes`, in the same
+    /// order, working on as many blueprints at once as there are threads
+    pub fn most_geodes(&self, blueprints: &[Blueprint], minutes: u8) -> Vec<u16> {
+        let threads = self.threads.get().min(blueprints.len());
+        // index of the next blueprint a thread can pick up
+        let next = AtomicUsize::new(0);
+        let mut geodes = thread::scope(|scope| {
+            let workers = (0..threads)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut cache = HashMap::new();
+                        let mut geodes = vec![];
+                        loop {
+                            let i = next.fetch_add(1, Ordering::Relaxed);
+                            let Some(blueprint) = blueprints.get(i) else {
+                                return geodes;
+                            };
+                            geodes.push((i, self.search(blueprint, minutes, &mut cache)));
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().expect("Workers don't panic"))
+                .collect::<Vec<_>>()
+        });
+        geodes.sort_unstable();
+        geodes.into_iter().map(|(_, geodes)| geodes).collect()
+    }
+
+    /// Most geodes `blueprint` can open in `minutes`, `cache` is the thread's
+    /// own for the memoised search
+    fn search(&self, blueprint: &Blueprint, minutes: u8, cache: &mut HashMap<State, u16>) -> u16 {
+        let state = State::new(blueprint, minutes);
+        match self.search {
+            Search::Memoised => {
+                cache.clear();
+                dfs(blueprint, state, cache)
+            }
+            Search::BranchAndBound => {
+                let mut best = 0;
+                branch_and_bound(blueprint, state, &mut best);
+                best
+            }
+        }
+    }
+}
+
+pub fn process_part1(input: &str) -> Answer {
+    Day19::default().solve_part1(input).unwrap().into()
+}
+
+pub fn process_part2(input: &str) -> Answer {
+    Day19::default().solve_part2(input).unwrap().into()
+}
+
+/// Robots a factory can build, what they cost and what they collect
+pub struct Blueprint {
+    id: u32,
+    /// Names of the resources, the index of a name stands for the resource
+    resources: Vec<String>,
+    recipes: Vec<Recipe>,
+    /// Resource to collect as much of as possible
+    goal: usize,
+    /// Most of each resource that can be spent in a minute, building any robot
+    max_spend: [u16; MAX_RESOURCES],
 }
 
-struct Blueprint {
-    id: u8,
-    costs: [Vec<(usize, u8)>; 4], // (robot_type, cost)
-    max_spend: [u16; 3],          // (ore, clay, obsidian)
+/// A robot collecting `robot` that can be built for `costs`
+struct Recipe {
+    robot: usize,
+    costs: Vec<(usize, u16)>,
 }
 
+/// Robot by the name of what it collects, with its costs by resource name
+type RawRecipe<'a> = (&'a str, Vec<(u16, &'a str)>);
+type Recipes<'a> = Vec<RawRecipe<'a>>;
+
 impl Blueprint {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        let (input, _) = tag("Blueprint ")(input)?;
-        let (input, id) = nomu8(input)?;
-        let (input, _) = tag(": Each ore robot costs ")(input)?;
-        let (input, ore_robot_cost) = Self::parse_resource(input)?;
-        let (input, _) = tag("Each clay robot costs ")(input)?;
-        let (input, clay_robot_cost) = Self::parse_resource(input)?;
-        let (input, _) = tag("Each obsidian robot costs ")(input)?;
-        let (input, obsidian_robot_cost) = pair(Self::parse_resource, Self::parse_resource)(input)?;
-        let (input, _) = tag("Each geode robot costs ")(input)?;
-        let (input, geode_robot_cost) = pair(Self::parse_resource, Self::parse_resource)(input)?;
-        let costs = [
-            vec![(ORE, ore_robot_cost)],
-            vec![(ORE, clay_robot_cost)],
-            vec![(ORE, obsidian_robot_cost.0), (CLAY, obsidian_robot_cost.1)],
-            vec![(ORE, geode_robot_cost.0), (OBSIDIAN, geode_robot_cost.1)],
-        ];
-        let max_spend = costs
+    /// Blueprint `id` from `recipes`, written at `rest` in `input`, where
+    /// resources get numbered in the order they come up
+    fn new(
+        input: &str,
+        rest: &str,
+        id: u32,
+        recipes: Recipes,
+        goal: &str,
+    ) -> Result<Self, ParseError> {
+        let mut resources: Vec<String> = vec![];
+        let mut index = |name: &str| match resources.iter().position(|r| r == name) {
+            Some(i) => i,
+            None => {
+                resources.push(name.to_string());
+                resources.len() - 1
+            }
+        };
+        let recipes = recipes
+            .into_iter()
+            .map(|(robot, costs)| Recipe {
+                robot: index(robot),
+                costs: costs
+                    .into_iter()
+                    .map(|(cost, resource)| (index(resource), cost))
+                    .collect(),
+            })
+            .collect::<Vec<_>>();
+        if resources.len() > MAX_RESOURCES {
+            let expected = format!("at most {MAX_RESOURCES} kinds of resources");
+            return Err(ParseError::at(input, rest, expected));
+        }
+        let goal = recipes
             .iter()
-            .flat_map(|v| v.iter())
-            .fold([0, 0, 0], |mut acc, &(i, v)| {
-                acc[i] = acc[i].max(v as u16);
-                acc
-            });
-
-        Ok((
-            input,
-            Self {
-                id,
-                costs,
-                max_spend,
-            },
-        ))
+            .map(|recipe| recipe.robot)
+            .find(|&robot| resources[robot] == goal)
+            .ok_or_else(|| ParseError::at(input, rest, format!("a {goal} robot")))?;
+        let mut max_spend = [0; MAX_RESOURCES];
+        for &(resource, cost) in recipes.iter().flat_map(|recipe| &recipe.costs) {
+            max_spend[resource] = max_spend[resource].max(cost);
+        }
+        Ok(Self {
+            id,
+            resources,
+            recipes,
+            goal,
+            max_spend,
+        })
     }
 
-    fn parse_resource(input: &str) -> IResult<&str, u8> {
-        let (input, value) = nomu8(input)?;
-        let (input, _) = space1(input)?;
-        let (input, _kind) = alt((tag("ore"), tag("clay"), tag("obsidian")))(input)?;
-        let (input, _) = alt((tag(". "), tag("."), tag(" and ")))(input)?;
-        Ok((input, value))
+    /// Names of the resources, in the order the blueprint first mentions them
+    pub fn resources(&self) -> &[String] {
+        &self.resources
     }
 
-    fn quality_level(&self, minutes_remaining: u8) -> u16 {
-        let state = State::new(minutes_remaining);
-        let mut cache = HashMap::new();
-        dfs(self, state, &mut cache) * self.id as u16
+    /// "Blueprint <id>:" followed by sentences like "Each <resource> robot
+    /// costs <n> <resource> and <n> <resource>." in any order, the first robot
+    /// is the one the factory starts with. Also gives back the input from the
+    /// start of the blueprint on, for pointing errors at it
+    fn parse(input: &str) -> IResult<&str, (&str, u32, Recipes<'_>)> {
+        let (rest, (id, recipes)) = pair(
+            delimited(tag("Blueprint "), nomu32, tag(":")),
+            many1(preceded(multispace1, Self::parse_recipe)),
+        )(input)?;
+        Ok((rest, (input, id, recipes)))
+    }
+
+    fn parse_recipe(input: &str) -> IResult<&str, RawRecipe<'_>> {
+        let (input, robot) = delimited(tag("Each "), alpha1, tag(" robot costs "))(input)?;
+        let cost = separated_pair(nomu16, space1, alpha1);
+        let (input, costs) = terminated(separated_list1(tag(" and "), cost), tag("."))(input)?;
+        Ok((input, (robot, costs)))
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Hash)]
 struct State {
     minutes_remaining: u8,
-    resources: [u16; 4], // [ore, clay, obsidian, geode]
-    robots: [u16; 4],
+    resources: [u16; MAX_RESOURCES],
+    robots: [u16; MAX_RESOURCES],
 }
 
 impl State {
-    fn new(minutes_remaining: u8) -> Self {
+    /// Nothing collected yet, with a robot of the first kind the blueprint lists
+    fn new(blueprint: &Blueprint, minutes_remaining: u8) -> Self {
+        let mut robots = [0; MAX_RESOURCES];
+        robots[blueprint.recipes[0].robot] = 1;
         Self {
             minutes_remaining,
-            resources: [0, 0, 0, 0],
-            robots: [1, 0, 0, 0],
+            resources: [0; MAX_RESOURCES],
+            robots,
         }
     }
 
     fn minimise(&mut self, blueprint: &Blueprint) {
-        self.resources
-            .iter_mut()
-            .zip(blueprint.max_spend.iter())
-            .for_each(|(resource, &max_resource)| {
+        for (resource, amount) in self.resources.iter_mut().enumerate() {
+            if resource != blueprint.goal {
                 // we can never spend more than this anyway, so we might as well pretend this is the maximum we have
-                *resource = (max_resource * self.minutes_remaining as u16).min(*resource)
-            });
+                let most =
+                    blueprint.max_spend[resource].saturating_mul(self.minutes_remaining as u16);
+                *amount = most.min(*amount);
+            }
+        }
     }
-}
 
-fn dfs(blueprint: &Blueprint, state: State, cache: &mut HashMap<State, u16>) -> u16 {
-    if state.minutes_remaining == 0 {
-        return state.resources[GEODE];
-    }
-    if let Some(result) = cache.get(&state) {
-        return *result;
+    /// Geodes, or whatever the goal is, collected by the end without building
+    /// anything else
+    fn idle_geodes(&self, blueprint: &Blueprint) -> u16 {
+        let goal = blueprint.goal;
+        self.resources[goal] + self.robots[goal] * self.minutes_remaining as u16
     }
 
-    // best result if we do nothing
-    let mut best_result =
-        state.resources[GEODE] + state.robots[GEODE] * state.minutes_remaining as u16;
+    /// Geodes collected by the end if a geode robot could be built every minute
+    fn optimistic_geodes(&self, blueprint: &Blueprint) -> u16 {
+        let minutes = self.minutes_remaining as u16;
+        self.idle_geodes(blueprint) + minutes * minutes.saturating_sub(1) / 2
+    }
 
-    blueprint.costs.iter().enumerate().for_each(|(typ, costs)| {
-        if typ < GEODE && state.robots[typ] >= blueprint.max_spend[typ] {
-            return; // continue
-        }
-        if let Ok(wait) = costs.iter().try_fold(0, |max, &(ctyp, cost)| {
-            let cost = cost as u16;
-            if state.robots[ctyp] == 0 {
-                Err(())
-            } else {
-                let wait =
-                    (cost.saturating_sub(state.resources[ctyp])).div_ceil(state.robots[ctyp]);
-                Ok(max.max(wait))
-            }
-        }) {
-            let time = wait + 1;
-            if let Some(remaining) = state.minutes_remaining.checked_sub(time as u8) {
-                if remaining == 0 {
-                    return; // continue
+    /// States right after building each robot worth building next, waiting
+    /// for the resources first, the robots listed last first as they tend to
+    /// be the ones collecting the goal
+    fn builds<'a>(&'a self, blueprint: &'a Blueprint) -> impl Iterator<Item = State> + 'a {
+        blueprint.recipes.iter().rev().filter_map(
+            move |&Recipe {
+                      robot: typ,
+                      ref costs,
+                  }| {
+                if typ != blueprint.goal && self.robots[typ] >= blueprint.max_spend[typ] {
+                    return None;
                 }
-                let mut robots = state.robots;
-                let mut resources = state.resources;
+                let wait = costs.iter().try_fold(0, |max, &(ctyp, cost)| {
+                    if self.robots[ctyp] == 0 {
+                        None
+                    } else {
+                        let wait =
+                            (cost.saturating_sub(self.resources[ctyp])).div_ceil(self.robots[ctyp]);
+                        Some(max.max(wait))
+                    }
+                })?;
+                let time = wait.saturating_add(1);
+                // a robot ready with no minutes left collects nothing
+                if time >= u16::from(self.minutes_remaining) {
+                    return None;
+                }
+                let remaining = self.minutes_remaining - time as u8;
+                let mut robots = self.robots;
+                let mut resources = self.resources;
                 resources.iter_mut().enumerate().for_each(|(typ, amount)| {
                     *amount += robots[typ] * time;
                 });
                 costs.iter().for_each(|&(ctyp, cost)| {
-                    resources[ctyp] -= cost as u16;
+                    resources[ctyp] -= cost;
                 });
                 robots[typ] += 1;
                 let mut new_state = State {
@@ -166,17 +338,43 @@ fn dfs(blueprint: &Blueprint, state: State, cache: &mut HashMap<State, u16>) ->
                     robots,
                 };
                 new_state.minimise(blueprint);
-                best_result = best_result.max(dfs(blueprint, new_state, cache));
-            }
-        }
-    });
+                Some(new_state)
+            },
+        )
+    }
+}
+
+fn dfs(blueprint: &Blueprint, state: State, cache: &mut HashMap<State, u16>) -> u16 {
+    if state.minutes_remaining == 0 {
+        return state.resources[blueprint.goal];
+    }
+    if let Some(result) = cache.get(&state) {
+        return *result;
+    }
+
+    // best result if we do nothing
+    let mut best_result = state.idle_geodes(blueprint);
+    for new_state in state.builds(blueprint) {
+        best_result = best_result.max(dfs(blueprint, new_state, cache));
+    }
     cache.insert(state, best_result);
     best_result
 }
 
-fn parse_input(input: &str) -> IResult<&str, Vec<Blueprint>> {
-    let (input, lines) = separated_list1(newline, Blueprint::parse)(input)?;
-    Ok((input, lines))
+/// Raises `best` to the most geodes that can be opened from `state`, giving up
+/// on states that couldn't beat it even if everything went their way
+fn branch_and_bound(blueprint: &Blueprint, state: State, best: &mut u16) {
+    *best = (*best).max(state.idle_geodes(blueprint));
+    if state.optimistic_geodes(blueprint) <= *best {
+        return;
+    }
+    for new_state in state.builds(blueprint) {
+        branch_and_bound(blueprint, new_state, best);
+    }
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<(&str, u32, Recipes<'_>)>> {
+    separated_list1(multispace1, Blueprint::parse)(input)
 }
 
 #[cfg(test)]
@@ -188,13 +386,136 @@ mod tests {
     fn part1() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part1(&input);
-        assert_eq!(result, "33");
+        assert_eq!(result, Answer::Integer(33));
     }
 
     #[test]
     fn part2() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part2(&input);
-        assert_eq!(result, "3472");
+        assert_eq!(result, Answer::Integer(3472));
+    }
+
+    #[test]
+    fn threads() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        let mut day = Day19::default();
+        // alternating blueprints, so answers that end up in the wrong place show
+        let blueprints = (0..5)
+            .map(|i| {
+                let line = input.lines().nth(i % 2).unwrap();
+                day.load(line).unwrap().remove(0)
+            })
+            .collect::<Vec<_>>();
+        day.configure("threads", "1").unwrap();
+        let serial = day.most_geodes(&blueprints, 21);
+        assert_eq!(serial, [3, 4, 3, 4, 3]);
+        for threads in ["2", "3", "8"] {
+            day.configure("threads", threads).unwrap();
+            assert_eq!(day.most_geodes(&blueprints, 21), serial);
+        }
+        assert!(day.configure("threads", "0").is_err());
+        assert_eq!(day.most_geodes(&[], 24), []);
+    }
+
+    #[test]
+    fn searches_agree() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        let mut day = Day19::default();
+        let blueprints = day.load(&input).unwrap();
+        assert_eq!(day.most_geodes(&blueprints, 24), [9, 12]);
+        assert_eq!(day.most_geodes(&blueprints, 32), [56, 62]);
+        day.configure("search", "memoised").unwrap();
+        for minutes in [1, 10, 21] {
+            let memoised = day.most_geodes(&blueprints, minutes);
+            day.search = Search::BranchAndBound;
+            assert_eq!(day.most_geodes(&blueprints, minutes), memoised);
+            day.search = Search::Memoised;
+        }
+        assert!(day.configure("search", "guess").is_err());
+    }
+
+    #[test]
+    fn recipes() {
+        // the first example blueprint with its resources renamed, the
+        // sentences shuffled apart from the robot the factory starts with
+        // and the sentences wrapped over lines
+        let input = "\
+Blueprint 1:
+  Each sand robot costs 4 sand.
+  Each gold robot costs 7 glass and 2 sand.
+  Each glass robot costs 3 sand and 14 water.
+  Each water robot costs 2 sand.";
+        let mut day = Day19::default();
+        assert!(day.load(input).is_err());
+        day.configure("goal", "gold").unwrap();
+        let blueprints = day.load(input).unwrap();
+        assert_eq!(
+            blueprints[0].resources(),
+            ["sand", "gold", "glass", "water"]
+        );
+        assert_eq!(day.part1(&blueprints), 9);
+
+        // a geode robot every minute from the second on
+        let cheap = "Blueprint 7: Each ore robot costs 100 ore. Each geode robot costs 1 ore.";
+        assert_eq!(process_part1(cheap), Answer::Integer(22 * 23 / 2 * 7));
+        // costs more than 255 that 24 minutes of ore can never pay for
+        let dear = "Blueprint 1: Each ore robot costs 257 ore. Each geode robot costs 257 ore.";
+        let mut day = Day19::default();
+        assert_eq!(day.part1(&day.load(dear).unwrap()), 0);
+        day.search = Search::Memoised;
+        assert_eq!(day.part1(&day.load(dear).unwrap()), 0);
+
+        // a fifth resource made of geodes
+        let input = fs::read_to_string("./example.txt").unwrap();
+        let input = input.replace(
+            "\nBlueprint",
+            " Each diamond robot costs 1 geode and 2 obsidian.\nBlueprint",
+        ) + " Each diamond robot costs 2 geode.";
+        let mut day = Day19::default();
+        day.configure("goal", "diamond").unwrap();
+        let blueprints = day.load(&input).unwrap();
+        assert_eq!(blueprints[1].resources().len(), 5);
+        let diamonds = day.most_geodes(&blueprints, 24);
+        assert!(diamonds.iter().all(|&d| d > 0));
+        day.search = Search::Memoised;
+        assert_eq!(day.most_geodes(&blueprints, 24), diamonds);
+    }
+
+    #[test]
+    fn recipe_errors() {
+        let day = Day19::default();
+        let error = day
+            .load("Blueprint 1: Each ore robot costs 1 ore.\nBlueprint 2: Each ore robot costs 2 ore.")
+            .err().unwrap();
+        assert_eq!((error.line, error.expected.as_str()), (1, "a geode robot"));
+
+        let many = (0..9)
+            .map(|i| {
+                format!(
+                    " Each {} robot costs 1 ore.",
+                    "abcdefghi".chars().nth(i).unwrap()
+                )
+            })
+            .collect::<String>();
+        let error = day
+            .load(&format!(
+                "Blueprint 1: Each geode robot costs 1 ore.\nBlueprint 2:{many}"
+            ))
+            .err()
+            .unwrap();
+        assert_eq!(error.line, 2);
+        assert!(day
+            .load("Blueprint 1: Each geode robot costs ore.")
+            .is_err());
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            let blueprints = Day19::default().load(&variant).unwrap();
+            assert_eq!(blueprints.len(), 2);
+        }
     }
 }
@@ -1,43 +1,43 @@
+use common::{parse_all, Answer, ParseError, Solution};
 use nom::{
     character::complete::{i64 as nomi64, newline},
     multi::separated_list1,
     IResult,
 };
 
-pub fn process_part1(input: &str) -> String {
-    let (input, original_nums) = parse_input(input).unwrap();
-    assert!(input.is_empty());
-    let mut linked_list = original_nums.into_iter().map(Node::new).collect::<Vec<_>>();
-    let width = linked_list.len();
-    (0..width).for_each(|i| {
-        let left = (i + width - 1) % width;
-        let right = (i + 1) % width;
-        linked_list[i].left = left;
-        linked_list[i].right = right;
-    });
-    debug_assert!(linked_list
-        .iter()
-        .all(|node| node.left < width && node.right < width));
+pub struct Day20;
 
-    mix(&mut linked_list);
-    let mut pos = linked_list
-        .iter()
-        .position(|node| node.value == 0)
-        .expect("There's always a zero");
-    let mut sum = 0;
-    (0..3).for_each(|_| {
-        (0..1000).for_each(|_| pos = linked_list[pos].right);
-        sum += linked_list[pos].value;
-    });
+impl Solution for Day20 {
+    type Input = Vec<i64>;
+    type Output = i64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_all(input, parse_input)
+    }
+
+    fn part1(&self, nums: &Self::Input) -> Self::Output {
+        grove_coordinates(nums, 1, 1)
+    }
+
+    fn part2(&self, nums: &Self::Input) -> Self::Output {
+        grove_coordinates(nums, 811589153, 10)
+    }
+}
 
-    sum.to_string()
+pub fn process_part1(input: &str) -> Answer {
+    Day20.solve_part1(input).unwrap().into()
 }
 
-pub fn process_part2(input: &str) -> String {
-    let decryption_key = 811589153_i64;
-    let (input, original_nums) = parse_input(input).unwrap();
-    assert!(input.is_empty());
-    let mut linked_list = original_nums.into_iter().map(Node::new).collect::<Vec<_>>();
+pub fn process_part2(input: &str) -> Answer {
+    Day20.solve_part2(input).unwrap().into()
+}
+
+fn grove_coordinates(original_nums: &[i64], decryption_key: i64, rounds: usize) -> i64 {
+    let mut linked_list = original_nums
+        .iter()
+        .copied()
+        .map(Node::new)
+        .collect::<Vec<_>>();
     let width = linked_list.len();
     (0..width).for_each(|i| {
         let left = (i + width - 1) % width;
@@ -50,7 +50,7 @@ pub fn process_part2(input: &str) -> String {
         .iter()
         .all(|node| node.left < width && node.right < width));
 
-    (0..10).for_each(|_| mix(&mut linked_list));
+    (0..rounds).for_each(|_| mix(&mut linked_list));
     let mut pos = linked_list
         .iter()
         .position(|node| node.value == 0)
@@ -61,7 +61,7 @@ pub fn process_part2(input: &str) -> String {
         sum += linked_list[pos].value;
     });
 
-    sum.to_string()
+    sum
 }
 
 struct Node {
@@ -141,13 +141,21 @@ mod tests {
     fn part1() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part1(&input);
-        assert_eq!(result, "3");
+        assert_eq!(result, Answer::Integer(3));
     }
 
     #[test]
     fn part2() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part2(&input);
-        assert_eq!(result, "1623178306");
+        assert_eq!(result, Answer::Integer(1623178306));
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Integer(3));
+        }
     }
 }
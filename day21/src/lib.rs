@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use common::{parse_all, Answer, ParseError, Solution};
 use nom::{
     bytes::complete::tag,
     character::complete::{alpha1, newline, one_of, space1, u64 as nomu64},
@@ -8,16 +9,47 @@ use nom::{
     IResult,
 };
 
-pub fn process_part1(input: &str) -> String {
-    let (input, monkey_vec) = parse_input(input).unwrap();
-    assert!(input.is_empty());
-    let monkey_names = monkey_vec.iter().map(|(name, _)| *name).collect::<Vec<_>>();
-    let mut monkeys = monkey_vec.into_iter().collect::<HashMap<&str, Monkey>>();
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<(String, Monkey)>;
+    type Output = isize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_all(input, parse_input)
+    }
+
+    fn part1(&self, monkeys: &Self::Input) -> Self::Output {
+        root_value(monkeys)
+    }
+
+    fn part2(&self, monkeys: &Self::Input) -> Self::Output {
+        human_value(monkeys)
+    }
+}
+
+pub fn process_part1(input: &str) -> Answer {
+    Day21.solve_part1(input).unwrap().into()
+}
+
+pub fn process_part2(input: &str) -> Answer {
+    Day21.solve_part2(input).unwrap().into()
+}
+
+fn root_value(monkey_vec: &[(String, Monkey)]) -> isize {
+    let monkey_names = monkey_vec
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>();
+    let mut monkeys = monkey_vec
+        .iter()
+        .map(|(name, monkey)| (name.as_str(), monkey.clone()))
+        .collect::<HashMap<&str, Monkey>>();
     loop {
         monkey_names.iter().for_each(|&monkey| {
             if let Some(Monkey::Operation { left, right, op }) = monkeys.get(monkey) {
                 if let (Some(Monkey::Value(l)), Some(Monkey::Value(r))) =
-                    (monkeys.get(left), monkeys.get(right))
+                    (monkeys.get(left.as_str()), monkeys.get(right.as_str()))
                 {
                     {
                         let value = op.apply(*l, *r);
@@ -30,15 +62,16 @@ pub fn process_part1(input: &str) -> String {
             }
         });
         if let Some(Monkey::Value(value)) = monkeys.get("root") {
-            return value.to_string();
+            return *value;
         }
     }
 }
 
-pub fn process_part2(input: &str) -> String {
-    let (input, monkey_vec) = parse_input(input).unwrap();
-    assert!(input.is_empty());
-    let monkeys = monkey_vec.into_iter().collect::<HashMap<&str, Monkey>>();
+fn human_value(monkey_vec: &[(String, Monkey)]) -> isize {
+    let monkeys = monkey_vec
+        .iter()
+        .map(|(name, monkey)| (name.as_str(), monkey.clone()))
+        .collect::<HashMap<&str, Monkey>>();
     let root = Node::from_monkeys("root", &monkeys);
     match root {
         Node::Equals {
@@ -76,7 +109,6 @@ pub fn process_part2(input: &str) -> String {
                                 Operation::Multiply => value / *x,
                                 Operation::Divide => value * *x,
                             }
-                            .to_string()
                         }
                         (Node::Value(x), Node::Human) => {
                             return match operation {
@@ -85,7 +117,6 @@ pub fn process_part2(input: &str) -> String {
                                 Operation::Multiply => value / *x,
                                 Operation::Divide => *x / value,
                             }
-                            .to_string()
                         }
                         (Node::Human, other) | (other, Node::Human) => loop {
                             if other.resolve() {
@@ -99,7 +130,7 @@ pub fn process_part2(input: &str) -> String {
                                 Operation::Multiply => value / *x,
                                 Operation::Divide => value * *x,
                             };
-                            arm = Box::new(other.clone());
+                            *arm = other.clone();
                         }
                         (Node::Value(x), other) => {
                             value = match operation {
@@ -108,7 +139,7 @@ pub fn process_part2(input: &str) -> String {
                                 Operation::Multiply => value / *x,
                                 Operation::Divide => *x / value,
                             };
-                            arm = Box::new(other.clone());
+                            *arm = other.clone();
                         }
                         (Node::Operation { .. }, Node::Operation { .. }) => {
                             left.resolve();
@@ -133,7 +164,7 @@ pub fn process_part2(input: &str) -> String {
 }
 
 #[derive(Debug, Clone, Copy)]
-enum Operation {
+pub enum Operation {
     Plus,
     Minus,
     Multiply,
@@ -163,25 +194,32 @@ impl Operation {
     }
 }
 
-#[derive(Debug)]
-enum Monkey<'a> {
+#[derive(Debug, Clone)]
+pub enum Monkey {
     Value(isize),
     Operation {
-        left: &'a str,
-        right: &'a str,
+        left: String,
+        right: String,
         op: Operation,
     },
 }
 
-impl<'a> Monkey<'a> {
-    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+impl Monkey {
+    fn parse(input: &str) -> IResult<&str, Self> {
         if let Ok((input, value)) = nomu64::<_, ()>(input) {
             return Ok((input, Self::Value(value as isize)));
         }
         let (input, left) = terminated(alpha1, space1)(input)?;
         let (input, op) = terminated(Operation::parse, space1)(input)?;
         let (input, right) = alpha1(input)?;
-        Ok((input, Self::Operation { left, right, op }))
+        Ok((
+            input,
+            Self::Operation {
+                left: left.to_string(),
+                right: right.to_string(),
+                op,
+            },
+        ))
     }
 
     fn set(&mut self, value: isize) {
@@ -256,15 +294,15 @@ impl Node {
     }
 }
 
-fn parse_input(input: &str) -> IResult<&str, Vec<(&str, Monkey)>> {
+fn parse_input(input: &str) -> IResult<&str, Vec<(String, Monkey)>> {
     let (input, lines) = separated_list1(newline, parse_line)(input)?;
     Ok((input, lines))
 }
 
-fn parse_line(input: &str) -> IResult<&str, (&str, Monkey)> {
+fn parse_line(input: &str) -> IResult<&str, (String, Monkey)> {
     let (input, name) = terminated(alpha1, tag(": "))(input)?;
     let (input, monkey) = Monkey::parse(input)?;
-    Ok((input, (name, monkey)))
+    Ok((input, (name.to_string(), monkey)))
 }
 
 #[cfg(test)]
@@ -276,13 +314,21 @@ mod tests {
     fn part1() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part1(&input);
-        assert_eq!(result, "152");
+        assert_eq!(result, Answer::Integer(152));
     }
 
     #[test]
     fn part2() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part2(&input);
-        assert_eq!(result, "301");
+        assert_eq!(result, Answer::Integer(301));
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Integer(152));
+        }
     }
 }
@@ -1,5 +1,6 @@
 use std::{collections::HashMap, ops::Index};
 
+use common::{parse_all, Answer, Direction, Grid, ParseError, Point, Point2, Solution};
 use nom::{
     bytes::complete::tag,
     character::complete::{newline, one_of, u16 as nomu16},
@@ -8,31 +9,44 @@ use nom::{
     IResult,
 };
 
-pub fn process_part1(input: &str) -> String {
-    let (input, (cave, instructions)) = parse_input(input).unwrap();
-    assert!(input.is_empty());
-    let start = cave.start();
-    let mut state = State::new(start);
-    instructions
-        .into_iter()
-        .for_each(|instruction| state = cave.walk(state, instruction));
-    let password = state.password();
-    password.to_string()
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = (Cave, Vec<Instruction>);
+    type Output = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_all(input, parse_input)
+    }
+
+    fn part1(&self, (cave, instructions): &Self::Input) -> Self::Output {
+        let start = cave.start();
+        let mut state = State::new(start);
+        instructions
+            .iter()
+            .for_each(|&instruction| state = cave.walk(state, instruction));
+        state.password()
+    }
+
+    fn part2(&self, (cave, instructions): &Self::Input) -> Self::Output {
+        let mut cube: Cube = cave.into();
+        instructions
+            .iter()
+            .for_each(|&instruction| cube.walk(instruction));
+        cube.state.password()
+    }
 }
 
-pub fn process_part2(input: &str) -> String {
-    let (input, (cave, instructions)) = parse_input(input).unwrap();
-    assert!(input.is_empty());
-    let mut cube: Cube = cave.into();
-    instructions
-        .into_iter()
-        .for_each(|instruction| cube.walk(instruction));
-    let password = cube.state.password();
-    password.to_string()
+pub fn process_part1(input: &str) -> Answer {
+    Day22.solve_part1(input).unwrap().into()
 }
 
-#[derive(Debug, PartialEq, Eq)]
-enum Tile {
+pub fn process_part2(input: &str) -> Answer {
+    Day22.solve_part2(input).unwrap().into()
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Tile {
     Empty,
     Open,
     Wall,
@@ -52,7 +66,7 @@ impl Tile {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-enum Instruction {
+pub enum Instruction {
     R,
     L,
     Move(usize),
@@ -73,19 +87,17 @@ impl Instruction {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
-enum Direction {
-    Right,
-    Down,
-    Left,
-    Up,
+trait Turn {
+    fn turn(&mut self, instruction: Instruction);
+
+    fn turned(&self, instruction: Instruction) -> Self;
 }
 
-impl Direction {
+impl Turn for Direction {
     fn turn(&mut self, instruction: Instruction) {
         match instruction {
-            Instruction::R => *self = (usize::from(*self) + 1).into(),
-            Instruction::L => *self = ((usize::from(*self) + 3) % 4).into(),
+            Instruction::R => *self = self.turn_right(),
+            Instruction::L => *self = self.turn_left(),
             Instruction::Move(_) => {}
         }
     }
@@ -95,54 +107,11 @@ impl Direction {
         t.turn(instruction);
         t
     }
-
-    fn reverse(&self) -> Self {
-        match self {
-            Self::Right => Self::Left,
-            Self::Down => Self::Up,
-            Self::Left => Self::Right,
-            Self::Up => Self::Down,
-        }
-    }
-}
-
-impl From<usize> for Direction {
-    fn from(value: usize) -> Self {
-        match value {
-            0 | 4.. => Self::Right,
-            1 => Self::Down,
-            2 => Self::Left,
-            3 => Self::Up,
-        }
-    }
-}
-
-impl From<Direction> for usize {
-    fn from(value: Direction) -> Self {
-        match value {
-            Direction::Right => 0,
-            Direction::Down => 1,
-            Direction::Left => 2,
-            Direction::Up => 3,
-        }
-    }
-}
-
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
-struct Point {
-    x: usize,
-    y: usize,
-}
-
-impl Point {
-    fn new(x: usize, y: usize) -> Self {
-        Self { x, y }
-    }
 }
 
 #[derive(Debug)]
-struct Cave {
-    tiles: Vec<Vec<Tile>>,
+pub struct Cave {
+    tiles: Grid<Tile>,
     y_spans: Vec<(usize, usize)>,
     x_spans: Vec<(usize, usize)>,
 }
@@ -160,9 +129,9 @@ impl Cave {
         tiles
             .iter_mut()
             .for_each(|row| row.resize_with(width, || Tile::Empty));
-        assert!(tiles.iter().all(|row| row.len() == width));
+        let tiles = Grid::from_rows(tiles).expect("Rows are padded to the same width");
         let x_spans = tiles
-            .iter()
+            .rows()
             .map(|row| {
                 let first = row
                     .iter()
@@ -198,14 +167,15 @@ impl Cave {
         let y_spans = (0..width)
             .map(|x| {
                 let first = (0..height)
-                    .find(|&y| tiles[y][x] != Tile::Empty)
+                    .find(|&y| tiles[Point::new(x, y)] != Tile::Empty)
                     .expect("There are no empty columns");
-                let last =
-                    if let Some(y) = ((first + 1)..height).find(|&y| tiles[y][x] == Tile::Empty) {
-                        y - 1
-                    } else {
-                        height - 1
-                    };
+                let last = if let Some(y) =
+                    ((first + 1)..height).find(|&y| tiles[Point::new(x, y)] == Tile::Empty)
+                {
+                    y - 1
+                } else {
+                    height - 1
+                };
                 (first, last)
             })
             .collect();
@@ -220,7 +190,9 @@ impl Cave {
     }
 
     fn start(&self) -> Point {
-        let x = self.tiles[0]
+        let x = self
+            .tiles
+            .row(0)
             .iter()
             .enumerate()
             .find_map(|(i, tile)| match tile {
@@ -306,7 +278,7 @@ struct ZipCorner {
 
 #[derive(Debug)]
 struct Cube {
-    tiles: Vec<Vec<Tile>>,
+    tiles: Grid<Tile>,
     edge_transformations: HashMap<State, State>,
     state: State,
 }
@@ -328,36 +300,9 @@ impl Cube {
                 Tile::Open => self.state = new_state,
                 Tile::Wall => {}
             }
-        } else if let Some(new_point) = match self.state.direction {
-            Direction::Right => {
-                if (self.state.position.x + 1) < self.tiles[0].len() {
-                    Some(Point::new(self.state.position.x + 1, self.state.position.y))
-                } else {
-                    None
-                }
-            }
-            Direction::Down => {
-                if (self.state.position.y + 1) < self.tiles.len() {
-                    Some(Point::new(self.state.position.x, self.state.position.y + 1))
-                } else {
-                    None
-                }
-            }
-            Direction::Left => {
-                if self.state.position.x > 0 {
-                    Some(Point::new(self.state.position.x - 1, self.state.position.y))
-                } else {
-                    None
-                }
-            }
-            Direction::Up => {
-                if self.state.position.y > 0 {
-                    Some(Point::new(self.state.position.x, self.state.position.y - 1))
-                } else {
-                    None
-                }
-            }
-        } {
+        } else if let Some(new_point) =
+            neighbour(&self.tiles, self.state.position, self.state.direction)
+        {
             match self.tiles[new_point] {
                 Tile::Empty => {
                     unreachable!("Should transform before walking off the edge!")
@@ -369,10 +314,10 @@ impl Cube {
     }
 }
 
-impl From<Cave> for Cube {
-    fn from(value: Cave) -> Self {
+impl From<&Cave> for Cube {
+    fn from(value: &Cave) -> Self {
         let state = State::new(value.start());
-        let tiles = value.tiles;
+        let tiles = value.tiles.clone();
         let mut corners = Vec::new();
         value.x_spans.windows(2).enumerate().for_each(|(y, w)| {
             let (left1, left2) = (w[0].0, w[1].0);
@@ -447,12 +392,13 @@ impl State {
     }
 
     fn password(self) -> usize {
-        1000 * (self.position.y + 1) + 4 * (self.position.x + 1) + usize::from(self.direction)
+        // facing counts clockwise from right, like `Direction::ALL`
+        1000 * (self.position.y + 1) + 4 * (self.position.x + 1) + self.direction as usize
     }
 }
 
 struct Zipper<'tiles> {
-    tiles: &'tiles Vec<Vec<Tile>>,
+    tiles: &'tiles Grid<Tile>,
     corners: Vec<ZipCorner>,
     current_corner: usize,
     #[allow(dead_code)] // for if I ever implement the edge case
@@ -473,9 +419,9 @@ struct Zipper<'tiles> {
 }
 
 impl<'tiles> Zipper<'tiles> {
-    fn new(tiles: &'tiles Vec<Vec<Tile>>, corners: Vec<ZipCorner>) -> Self {
-        let width = tiles[0].len();
-        let height = tiles.len();
+    fn new(tiles: &'tiles Grid<Tile>, corners: Vec<ZipCorner>) -> Self {
+        let width = tiles.width();
+        let height = tiles.height();
 
         let side_length = {
             if width * 4 == height * 3 {
@@ -532,32 +478,32 @@ impl<'tiles> Zipper<'tiles> {
     }
 
     fn arm_iter(
-        tiles: &'tiles [Vec<Tile>],
+        tiles: &'tiles Grid<Tile>,
         start: Point,
         arm: (Direction, Direction),
     ) -> Box<dyn Iterator<Item = Point> + 'tiles> {
         match arm.0 {
             Direction::Right => Box::new(
-                ((start.x + 1)..tiles[0].len())
+                ((start.x + 1)..tiles.width())
                     .map(move |x| Point::new(x, start.y))
-                    .take_while(|p| tiles[p.y][p.x] != Tile::Empty),
+                    .take_while(|&p| tiles[p] != Tile::Empty),
             ),
             Direction::Down => Box::new(
-                ((start.y + 1)..tiles.len())
+                ((start.y + 1)..tiles.height())
                     .map(move |y| Point::new(start.x, y))
-                    .take_while(|p| tiles[p.y][p.x] != Tile::Empty),
+                    .take_while(|&p| tiles[p] != Tile::Empty),
             ),
             Direction::Left => Box::new(
                 (0..start.x)
                     .rev()
                     .map(move |x| Point::new(x, start.y))
-                    .take_while(|p| tiles[p.y][p.x] != Tile::Empty),
+                    .take_while(|&p| tiles[p] != Tile::Empty),
             ),
             Direction::Up => Box::new(
                 (0..start.y)
                     .rev()
                     .map(move |y| Point::new(start.x, y))
-                    .take_while(|p| tiles[p.y][p.x] != Tile::Empty),
+                    .take_while(|&p| tiles[p] != Tile::Empty),
             ),
         }
     }
@@ -689,59 +635,16 @@ impl<'a> Iterator for Zipper<'a> {
     }
 }
 
-impl Index<Point> for Vec<Vec<Tile>> {
-    type Output = Tile;
-
-    fn index(&self, index: Point) -> &Self::Output {
-        &self[index.y][index.x]
-    }
+/// Neighbour of `point` in `direction`, if it's inside `tiles`
+fn neighbour(tiles: &Grid<Tile>, point: Point, direction: Direction) -> Option<Point> {
+    let neighbour = Point::try_from(Point2::from(point) + direction.offset()).ok()?;
+    tiles.contains(neighbour).then_some(neighbour)
 }
 
-#[allow(clippy::ptr_arg)]
-fn next(tiles: &Vec<Vec<Tile>>, point: Point, direction: Direction) -> Option<Point> {
-    let next_point = match direction {
-        Direction::Right => {
-            if point.x + 1 >= tiles[0].len() {
-                return None;
-            }
-            Point {
-                x: point.x + 1,
-                y: point.y,
-            }
-        }
-        Direction::Down => {
-            if point.y + 1 >= tiles.len() {
-                return None;
-            }
-            Point {
-                x: point.x,
-                y: point.y + 1,
-            }
-        }
-        Direction::Left => {
-            if point.x == 0 {
-                return None;
-            }
-            Point {
-                x: point.x - 1,
-                y: point.y,
-            }
-        }
-        Direction::Up => {
-            if point.y == 0 {
-                return None;
-            }
-            Point {
-                x: point.x,
-                y: point.y - 1,
-            }
-        }
-    };
-    if tiles[next_point] == Tile::Empty {
-        None
-    } else {
-        Some(next_point)
-    }
+/// Neighbour of `point` in `direction`, if it's on the map
+fn next(tiles: &Grid<Tile>, point: Point, direction: Direction) -> Option<Point> {
+    let next_point = neighbour(tiles, point, direction)?;
+    (tiles[next_point] != Tile::Empty).then_some(next_point)
 }
 
 fn parse_input(input: &str) -> IResult<&str, (Cave, Vec<Instruction>)> {
@@ -764,13 +667,21 @@ mod tests {
     fn part1() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part1(&input);
-        assert_eq!(result, "6032");
+        assert_eq!(result, Answer::Integer(6032));
     }
 
     #[test]
     fn part2() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part2(&input);
-        assert_eq!(result, "5031");
+        assert_eq!(result, Answer::Integer(5031));
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Integer(6032));
+        }
     }
 }
@@ -1,38 +1,53 @@
-use std::{
-    collections::{HashMap, HashSet},
-    ops::Add,
-};
+use std::collections::{HashMap, HashSet};
 
+use common::{Answer, Direction, Grid, ParseError, Point, Point2, Rect, Solution};
+
+/// Directions the elves consider, in the order of the first round
 const DIRECTIONS: [Direction; 4] = [
-    Direction::North,
-    Direction::South,
-    Direction::West,
-    Direction::East,
+    Direction::Up,
+    Direction::Down,
+    Direction::Left,
+    Direction::Right,
 ];
 
-const AROUND: [Elf; 8] = [
-    Elf { x: -1, y: -1 },
-    Elf { x: 0, y: -1 },
-    Elf { x: 1, y: -1 },
-    Elf { x: -1, y: 0 },
-    Elf { x: 1, y: 0 },
-    Elf { x: -1, y: 1 },
-    Elf { x: 0, y: 1 },
-    Elf { x: 1, y: 1 },
-];
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = HashSet<Point2>;
+    type Output = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, elves: &Self::Input) -> Self::Output {
+        empty_ground(elves.clone())
+    }
+
+    fn part2(&self, elves: &Self::Input) -> Self::Output {
+        first_idle_round(elves.clone())
+    }
+}
 
-pub fn process_part1(input: &str) -> String {
-    let mut elves = parse_input(input);
+pub fn process_part1(input: &str) -> Answer {
+    Day23.solve_part1(input).unwrap().into()
+}
+
+pub fn process_part2(input: &str) -> Answer {
+    Day23.solve_part2(input).unwrap().into()
+}
+
+fn empty_ground(mut elves: HashSet<Point2>) -> usize {
     let mut intentions = HashMap::new();
     for i in 0..10 {
         let directions = DIRECTIONS.iter().cycle().skip(i % 4).take(4);
         elves.iter().for_each(|elf| {
-            if !elf.gonna_move(&elves) {
+            if !gonna_move(*elf, &elves) {
                 intentions.entry(*elf).or_insert_with(Vec::new).push(*elf);
                 return; // continue
             }
             for direction in directions.clone() {
-                if let Some(new_elf) = elf.consider(direction, &elves) {
+                if let Some(new_elf) = consider(*elf, *direction, &elves) {
                     intentions
                         .entry(new_elf)
                         .or_insert_with(Vec::new)
@@ -51,23 +66,21 @@ pub fn process_part1(input: &str) -> String {
             }
         });
     }
-    let ground = count_ground(elves);
-    ground.to_string()
+    count_ground(elves)
 }
 
-pub fn process_part2(input: &str) -> String {
-    let mut elves = parse_input(input);
+fn first_idle_round(mut elves: HashSet<Point2>) -> usize {
     let mut previous_elves = HashSet::new();
     let mut intentions = HashMap::new();
     for i in 0.. {
         let directions = DIRECTIONS.iter().cycle().skip(i % 4).take(4);
         elves.iter().for_each(|elf| {
-            if !elf.gonna_move(&elves) {
+            if !gonna_move(*elf, &elves) {
                 intentions.entry(*elf).or_insert_with(Vec::new).push(*elf);
                 return; // continue
             }
             for direction in directions.clone() {
-                if let Some(new_elf) = elf.consider(direction, &elves) {
+                if let Some(new_elf) = consider(*elf, *direction, &elves) {
                     intentions
                         .entry(new_elf)
                         .or_insert_with(Vec::new)
@@ -87,129 +100,56 @@ pub fn process_part2(input: &str) -> String {
             }
         });
         if elves == previous_elves {
-            return (i + 1).to_string();
+            return i + 1;
         }
     }
     unreachable!();
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Elf {
-    x: isize,
-    y: isize,
-}
-
-impl Elf {
-    fn new(x: isize, y: isize) -> Self {
-        Self { x, y }
-    }
-
-    fn consider(&self, direction: &Direction, elves: &HashSet<Elf>) -> Option<Self> {
-        let steps = match direction {
-            Direction::North => [
-                Elf { x: -1, y: -1 },
-                Elf { x: 0, y: -1 },
-                Elf { x: 1, y: -1 },
-            ],
-            Direction::South => [Elf { x: -1, y: 1 }, Elf { x: 0, y: 1 }, Elf { x: 1, y: 1 }],
-            Direction::East => [Elf { x: 1, y: -1 }, Elf { x: 1, y: 0 }, Elf { x: 1, y: 1 }],
-            Direction::West => [
-                Elf { x: -1, y: -1 },
-                Elf { x: -1, y: 0 },
-                Elf { x: -1, y: 1 },
-            ],
-        };
-        if steps.iter().all(|step| !elves.contains(&(*self + *step))) {
-            Some(*self + steps[1])
-        } else {
-            None
-        }
-    }
-
-    fn gonna_move(&self, elves: &HashSet<Elf>) -> bool {
-        !AROUND.iter().all(|step| !elves.contains(&(*self + *step)))
+/// Where `elf` proposes to move when looking in `direction`, if nobody is in the way
+fn consider(elf: Point2, direction: Direction, elves: &HashSet<Point2>) -> Option<Point2> {
+    let ahead = elf + direction.offset();
+    let side = direction.turn_right().offset();
+    let steps = [ahead - side, ahead, ahead + side];
+    if steps.iter().all(|step| !elves.contains(step)) {
+        Some(ahead)
+    } else {
+        None
     }
 }
 
-impl Add<Elf> for Elf {
-    type Output = Self;
-
-    fn add(self, rhs: Elf) -> Self::Output {
-        Self {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
-    }
+fn gonna_move(elf: Point2, elves: &HashSet<Point2>) -> bool {
+    elf.neighbours8().any(|n| elves.contains(&n))
 }
 
-enum Direction {
-    North,
-    South,
-    East,
-    West,
-}
-
-fn parse_input(input: &str) -> HashSet<Elf> {
-    let (mut x, mut y) = (0_isize, 0_isize);
-    input
-        .chars()
-        .filter_map(|c| {
-            x += 1;
-            match c {
-                '.' => None,
-                '#' => Some(Elf::new(x, y)),
-                '\n' => {
-                    x = 0;
-                    y += 1;
-                    None
-                }
-                x => unreachable!("Invalid character: {x}"),
-            }
-        })
-        .collect::<HashSet<_>>()
+fn parse_input(input: &str) -> Result<HashSet<Point2>, ParseError> {
+    let map = Grid::parse(input, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    Ok(map
+        .iter()
+        .filter(|(_, &elf)| elf)
+        .map(|(p, _)| Point2::from(p))
+        .collect())
 }
 
-fn count_ground(elves: HashSet<Elf>) -> usize {
-    let (x_min, y_min, x_max, y_max) = elves.iter().fold(
-        (isize::MAX, isize::MAX, isize::MIN, isize::MIN),
-        |(x_min, y_min, x_max, y_max), el| {
-            (
-                x_min.min(el.x),
-                y_min.min(el.y),
-                x_max.max(el.x),
-                y_max.max(el.y),
-            )
-        },
-    );
-    let width = x_min.abs_diff(x_max) + 1;
-    let height = y_min.abs_diff(y_max) + 1;
-    width * height - elves.len()
+fn count_ground(elves: HashSet<Point2>) -> usize {
+    let bounds = Rect::from_points(elves.iter().copied()).expect("There are elves");
+    bounds.volume() as usize - elves.len()
 }
 
 #[allow(dead_code)]
-fn print_elves(elves: &HashSet<Elf>) {
-    let (x_min, y_min, x_max, y_max) = elves.iter().fold(
-        (isize::MAX, isize::MAX, isize::MIN, isize::MIN),
-        |(x_min, y_min, x_max, y_max), el| {
-            (
-                x_min.min(el.x),
-                y_min.min(el.y),
-                x_max.max(el.x),
-                y_max.max(el.y),
-            )
-        },
-    );
+fn print_elves(elves: &HashSet<Point2>) {
+    let bounds = Rect::from_points(elves.iter().copied()).expect("There are elves");
+    let mut map = Grid::new(bounds.width() as usize, bounds.height() as usize, '.');
+    elves.iter().for_each(|&elf| {
+        let p = Point::try_from(elf - bounds.min).expect("Elves are inside their bounds");
+        map[p] = '#';
+    });
     println!("-------------------------------------");
-    for y in y_min..=y_max {
-        for x in x_min..=x_max {
-            if elves.contains(&Elf { x, y }) {
-                print!("#");
-            } else {
-                print!(".");
-            }
-        }
-        println!();
-    }
+    println!("{map}");
     println!("-------------------------------------");
 }
 
@@ -222,13 +162,21 @@ mod tests {
     fn part1() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part1(&input);
-        assert_eq!(result, "110");
+        assert_eq!(result, Answer::Integer(110));
     }
 
     #[test]
     fn part2() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part2(&input);
-        assert_eq!(result, "20");
+        assert_eq!(result, Answer::Integer(20));
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Integer(110));
+        }
     }
 }
@@ -1,223 +1,160 @@
-use std::collections::HashSet;
-
-use once_cell::sync::OnceCell;
-
-static WIDTH: OnceCell<usize> = OnceCell::new();
-static HEIGHT: OnceCell<usize> = OnceCell::new();
-
-pub fn process_part1(input: &str) -> String {
-    let mut blizzards = parse_input(input);
-    let mut elves: HashSet<Point> = HashSet::new();
-    let mut next_elves = HashSet::new();
-    let target = Point::new(WIDTH.get().unwrap() - 1, HEIGHT.get().unwrap() - 1);
-    let start = Point::default();
-    for round in 1.. {
-        if elves.contains(&target) {
-            return round.to_string();
-        }
-        assert!(next_elves.is_empty());
-        next_elves.insert(start);
-        elves
-            .drain()
-            .for_each(|elf| next_elves.extend(elf.next_positions()));
-        // move the blizzards and kill all elves in the wrong positions
-        blizzards.iter_mut().for_each(|b| {
-            b.onward();
-            next_elves.remove(&b.position);
-        });
-        std::mem::swap(&mut elves, &mut next_elves);
+use std::iter;
+
+use common::{astar, Answer, Direction, Grid, ParseError, Point, Point2, Solution};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Valley;
+    type Output = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
     }
-    unreachable!()
-}
 
-pub fn process_part2(input: &str) -> String {
-    let mut blizzards = parse_input(input);
-    let mut elves: HashSet<Point> = HashSet::new();
-    let mut next_elves = HashSet::new();
-    let mut target = Point::new(WIDTH.get().unwrap() - 1, HEIGHT.get().unwrap() - 1);
-    let mut start = Point::default();
-    let mut targets = [start, target].into_iter();
-    for round in 1.. {
-        if elves.contains(&target) {
-            if let Some(new_target) = targets.next() {
-                start = target;
-                target = new_target;
-                elves.clear();
-            } else {
-                return round.to_string();
-            }
-        }
-        assert!(next_elves.is_empty());
-        next_elves.insert(start);
-        elves
-            .drain()
-            .for_each(|elf| next_elves.extend(elf.next_positions()));
-        // move the blizzards and kill all elves in the wrong positions
-        blizzards.iter_mut().for_each(|b| {
-            b.onward();
-            next_elves.remove(&b.position);
-        });
-        std::mem::swap(&mut elves, &mut next_elves);
+    fn part1(&self, valley: &Self::Input) -> Self::Output {
+        crossing_time(valley)
+    }
+
+    fn part2(&self, valley: &Self::Input) -> Self::Output {
+        round_trip_time(valley)
     }
-    unreachable!()
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
+pub fn process_part1(input: &str) -> Answer {
+    Day24.solve_part1(input).unwrap().into()
 }
 
-impl Direction {
-    fn as_char(self) -> char {
-        match self {
-            Direction::Up => '^',
-            Direction::Down => 'v',
-            Direction::Left => '<',
-            Direction::Right => '>',
-        }
-    }
+pub fn process_part2(input: &str) -> Answer {
+    Day24.solve_part2(input).unwrap().into()
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
-struct Point {
-    x: usize,
-    y: usize,
+fn crossing_time(valley: &Valley) -> usize {
+    let (entrance, exit) = valley.ends();
+    crossing(valley, entrance, exit, 0) + 1
 }
 
-impl Point {
-    fn new(x: usize, y: usize) -> Self {
-        Self { x, y }
-    }
+fn round_trip_time(valley: &Valley) -> usize {
+    let (entrance, exit) = valley.ends();
+    let there = crossing(valley, entrance, exit, 0);
+    let back = crossing(valley, exit, entrance, there);
+    crossing(valley, entrance, exit, back) + 1
+}
 
-    fn next_positions(&self) -> impl Iterator<Item = Self> {
-        let x_end = *WIDTH.get().unwrap() - 1;
-        let y_end = *HEIGHT.get().unwrap() - 1;
-        [
-            if self.x > 0 {
-                Some(Point::new(self.x - 1, self.y))
-            } else {
-                None
-            },
-            if self.y > 0 {
-                Some(Point::new(self.x, self.y - 1))
-            } else {
-                None
-            },
-            if self.x < x_end {
-                Some(Point::new(self.x + 1, self.y))
-            } else {
-                None
-            },
-            if self.y < y_end {
-                Some(Point::new(self.x, self.y + 1))
-            } else {
-                None
-            },
-            Some(*self),
-        ]
-        .into_iter()
-        .flatten()
-    }
+/// Minute the expedition reaches `to`, when waiting next to `from` at `minute`
+fn crossing(valley: &Valley, from: Point, to: Point, minute: usize) -> usize {
+    // `None` is waiting outside the valley, next to `from`
+    let start = (None, minute);
+    let moves = |&(position, minute): &(Option<Point>, usize)| {
+        let minute = minute + 1;
+        let candidates: Vec<_> = match position {
+            None => vec![None, Some(from)],
+            Some(p) => valley.next_positions(p).map(Some).collect(),
+        };
+        candidates
+            .into_iter()
+            .filter(move |&p| p.is_none_or(|p| valley.is_clear(p, minute)))
+            .map(move |p| ((p, minute), 1))
+    };
+    let distance = |&(position, _): &(Option<Point>, usize)| match position {
+        None => Point2::from(from).manhattan(Point2::from(to)) as usize + 1,
+        Some(p) => Point2::from(p).manhattan(Point2::from(to)) as usize,
+    };
+    let path = astar(start, moves, distance, |&(position, _)| {
+        position == Some(to)
+    })
+    .expect("There's a way through the valley");
+    minute + path.cost
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-struct Blizzard {
-    direction: Direction,
-    position: Point,
+/// The blizzards in the valley at minute 0, without its surrounding walls
+pub struct Valley {
+    blizzards: Grid<Option<Direction>>,
 }
 
-impl Blizzard {
-    fn onward(&mut self) {
-        let new_position = match self.direction {
-            Direction::Up => {
-                let height = HEIGHT.get().expect("Parsing has already been done");
-                Point::new(self.position.x, (self.position.y + height - 1) % height)
-            }
-            Direction::Down => {
-                let height = HEIGHT.get().expect("Parsing has already been done");
-                Point::new(self.position.x, (self.position.y + 1) % height)
-            }
-            Direction::Left => {
-                let width = WIDTH.get().expect("Parsing has already been done");
-                Point::new((self.position.x + width - 1) % width, self.position.y)
-            }
-            Direction::Right => {
-                let width = WIDTH.get().expect("Parsing has already been done");
-                Point::new((self.position.x + 1) % width, self.position.y)
-            }
-        };
-        self.position = new_position;
+impl Valley {
+    /// The first and last positions inside the valley
+    fn ends(&self) -> (Point, Point) {
+        let (width, height) = (self.blizzards.width(), self.blizzards.height());
+        (Point::default(), Point::new(width - 1, height - 1))
     }
 
-    fn as_vizzard(&self) -> BlizzardVizzard {
-        BlizzardVizzard::Single(self.direction)
+    /// Directions of the blizzards at `point` at `minute`
+    fn blizzards_at(&self, point: Point, minute: usize) -> impl Iterator<Item = Direction> + '_ {
+        let (width, height) = (self.blizzards.width(), self.blizzards.height());
+        let Point { x, y } = point;
+        // where a blizzard moving each way had to start to be here by now
+        [
+            (
+                Point::new((x + width - minute % width) % width, y),
+                Direction::Right,
+            ),
+            (Point::new((x + minute) % width, y), Direction::Left),
+            (
+                Point::new(x, (y + height - minute % height) % height),
+                Direction::Down,
+            ),
+            (Point::new(x, (y + minute) % height), Direction::Up),
+        ]
+        .into_iter()
+        .filter(|&(origin, direction)| self.blizzards[origin] == Some(direction))
+        .map(|(_, direction)| direction)
     }
-}
-
-#[derive(Clone)]
-enum BlizzardVizzard {
-    Nothing,
-    Single(Direction),
-    Multiple(usize),
-}
 
-impl BlizzardVizzard {
-    fn print(&self) {
-        match self {
-            BlizzardVizzard::Single(dir) => print!("{}", dir.as_char()),
-            BlizzardVizzard::Multiple(n) => print!("{n}"),
-            BlizzardVizzard::Nothing => print!("."),
-        }
+    fn is_clear(&self, point: Point, minute: usize) -> bool {
+        self.blizzards_at(point, minute).next().is_none()
     }
 
-    fn increase(&mut self, blizzard: &Blizzard) {
-        match self {
-            BlizzardVizzard::Nothing => *self = blizzard.as_vizzard(),
-            BlizzardVizzard::Single(_) => *self = Self::Multiple(2),
-            BlizzardVizzard::Multiple(n) => *self = Self::Multiple(*n + 1),
-        }
+    /// Positions reachable in one minute from `position`
+    fn next_positions(&self, position: Point) -> impl Iterator<Item = Point> + '_ {
+        self.blizzards
+            .neighbours4(position)
+            .chain(iter::once(position))
     }
 }
 
-fn parse_input(input: &str) -> Vec<Blizzard> {
-    let _ = HEIGHT.set(input.lines().count() - 2);
-    let _ = WIDTH.set(input.lines().next().expect("Field has multiple rows").len() - 2);
-    input
-        .lines()
+fn parse_input(input: &str) -> Result<Valley, ParseError> {
+    let map = Grid::parse(input, |c| {
+        ("#.".contains(c) || Direction::from_arrow(c).is_some()).then_some(c)
+    })?;
+    // the walls need a valley of at least one position between them
+    if map.width() < 3 {
+        return Err(ParseError::at(
+            input,
+            input,
+            "a valley at least 3 columns wide",
+        ));
+    }
+    if map.height() < 3 {
+        return Err(ParseError::at(input, "", "a valley at least 3 rows high"));
+    }
+    let rows = map
+        .rows()
         .skip(1)
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.char_indices().filter_map(move |(x, c)| {
-                match c {
-                    '^' => Some(Direction::Up),
-                    'v' => Some(Direction::Down),
-                    '<' => Some(Direction::Left),
-                    '>' => Some(Direction::Right),
-                    _ => None,
-                }
-                .map(|direction| Blizzard {
-                    direction,
-                    position: Point::new(x - 1, y),
-                })
-            })
+        .take(map.height().saturating_sub(2))
+        .map(|row| {
+            row[1..row.len() - 1]
+                .iter()
+                .map(|&c| Direction::from_arrow(c))
+                .collect()
         })
-        .collect()
+        .collect();
+    let blizzards = Grid::from_rows(rows).expect("Rows of a grid have the same length");
+    Ok(Valley { blizzards })
 }
 
 #[allow(dead_code)]
-fn print_blizzards(blizzards: &[Blizzard]) {
-    let mut field =
-        vec![vec![BlizzardVizzard::Nothing; *WIDTH.get().unwrap()]; *HEIGHT.get().unwrap()];
-    blizzards
-        .iter()
-        .for_each(|b| field[b.position.y][b.position.x].increase(b));
-    field.iter().for_each(|row| {
-        row.iter().for_each(|bv| bv.print());
-        println!()
-    });
+fn print_blizzards(valley: &Valley, minute: usize) {
+    let mut field = valley.blizzards.map(|_| '.');
+    for point in valley.blizzards.points() {
+        let blizzards = valley.blizzards_at(point, minute).collect::<Vec<_>>();
+        field[point] = match blizzards[..] {
+            [] => '.',
+            [direction] => direction.arrow(),
+            _ => char::from_digit(blizzards.len() as u32, 10).expect("At most 4 blizzards"),
+        };
+    }
+    println!("{field}");
     println!("----------------------------------------");
 }
 
@@ -230,13 +167,31 @@ mod tests {
     fn part1() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part1(&input);
-        assert_eq!(result, "18");
+        assert_eq!(result, Answer::Integer(18));
     }
 
     #[test]
     fn part2() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part2(&input);
-        assert_eq!(result, "54");
+        assert_eq!(result, Answer::Integer(54));
+    }
+
+    #[test]
+    fn too_small() {
+        let error = Day24.parse("#.#\n#.#").err().unwrap();
+        assert_eq!(error.expected, "a valley at least 3 rows high");
+        let error = Day24.parse("#\n.\n.\n#").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.expected, "a valley at least 3 columns wide");
+        assert!(Day24.parse("#.#\n#>#\n#.#").is_ok());
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Integer(18));
+        }
     }
 }
@@ -1,23 +1,40 @@
+use common::{parse_all, Answer, ParseError, Solution};
 use nom::{
     character::complete::{newline, one_of},
     multi::{many1, separated_list1},
     IResult,
 };
 
-pub fn process_part1(input: &str) -> String {
-    let (input, snafus) = parse_input(input).unwrap();
-    assert!(input.is_empty());
-    let snafu_sum = snafus.into_iter().sum::<Snafu>();
-    format!("{}", snafu_sum)
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<Snafu>;
+    type Output = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_all(input, parse_input)
+    }
+
+    fn part1(&self, snafus: &Self::Input) -> Self::Output {
+        snafus.iter().copied().sum::<Snafu>().to_string()
+    }
+
+    fn part2(&self, _snafus: &Self::Input) -> Self::Output {
+        // there is no part 2 the last day!
+        "".to_string()
+    }
 }
 
-pub fn process_part2(_input: &str) -> String {
-    // there is no part 2 the last day!
-    "".to_string()
+pub fn process_part1(input: &str) -> Answer {
+    Day25.solve_part1(input).unwrap().into()
 }
 
-#[derive(Debug, Default)]
-struct Snafu {
+pub fn process_part2(input: &str) -> Answer {
+    Day25.solve_part2(input).unwrap().into()
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Snafu {
     value: isize,
 }
 
@@ -160,6 +177,14 @@ mod tests {
     fn part1() {
         let input = fs::read_to_string("./example.txt").unwrap();
         let result = process_part1(&input);
-        assert_eq!(result, "2=-1=0");
+        assert_eq!(result, Answer::Text("2=-1=0".to_string()));
+    }
+
+    #[test]
+    fn input_variants() {
+        let input = fs::read_to_string("./example.txt").unwrap();
+        for variant in common::input_variants(&input) {
+            assert_eq!(process_part1(&variant), Answer::Text("2=-1=0".to_string()));
+        }
     }
 }